@@ -0,0 +1,244 @@
+//! The `cron!` macro, which checks a cron expression at compile time and expands to a ready `CronSchedule`
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use croncron::{CronDayMatching, CronDialect, CronDstPolicy, CronError, CronGapPolicy, CronNickname, CronOverlapPolicy, CronSchedule, Tz};
+use croncron::macro_support::{CronCommand, CronParts, StepBase};
+
+const COMMAND: &str = "::croncron::macro_support::CronCommand";
+const STEP_BASE: &str = "::croncron::macro_support::StepBase";
+
+/// Builds a `croncron::CronSchedule` from a cron expression checked at compile time.
+///
+/// The expression can be written as bare tokens or as a string literal, an invalid
+/// field is reported as a compile error pointing at that field.
+///
+/// ```
+/// use croncron_macros::cron;
+///
+/// let schedule = cron!( */15 9-17 * * MON-FRI );
+/// assert_eq!( schedule.to_string(), "*/15 9-17 * * 1-5" );
+///
+/// let schedule = cron!( "0 0 1,15 * *" );
+/// let nightly = cron!( @daily );
+/// ```
+///
+/// ```compile_fail
+/// use croncron_macros::cron;
+///
+/// let schedule = cron!( 0 24 * * * );
+/// ```
+#[proc_macro]
+pub fn cron( input: TokenStream ) -> TokenStream {
+    let fields = match split_fields( input ) {
+        Ok( fields ) => fields,
+        Err( ( message, span ) ) => return compile_error( &message, span )
+    };
+
+    let expression: Vec<&str> = fields.iter().map( |field| field.text.as_str() ).collect();
+
+    match expression.join( " " ).parse::<CronSchedule>() {
+        Ok( schedule ) => schedule_code( CronParts::from( schedule ) ).parse().unwrap(),
+        Err( error ) => compile_error( &error.to_string(), field_at( &fields, &error ) )
+    }
+}
+
+/// A field of the expression along with the span of its first token
+struct Field {
+    text: String,
+    span: Span
+}
+
+/// Groups the input into fields, tokens written without whitespace between them belong to the same field
+fn split_fields( input: TokenStream ) -> Result<Vec<Field>, ( String, Span )> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    if let [ TokenTree::Literal( literal ) ] = tokens.as_slice() {
+        let text = unquote( &literal.to_string() )
+            .ok_or_else( || ( "Expected a cron expression as tokens or a string literal".to_owned(), literal.span() ) )?;
+
+        let fields: Vec<Field> = text.split_whitespace().map( |part| Field{ text: part.to_owned(), span: literal.span() } ).collect();
+
+        if fields.is_empty() {
+            return Err( ( "Expected a cron expression".to_owned(), literal.span() ) );
+        }
+
+        return Ok( fields );
+    }
+
+    let mut fields: Vec<Field> = vec![];
+    let mut previous_end: Option<Span> = None;
+
+    for token in tokens {
+        if let TokenTree::Group( group ) = &token {
+            return Err( ( "Unexpected group in cron expression".to_owned(), group.span() ) );
+        }
+
+        let span = token.span();
+        let start = span.start();
+        let joined = previous_end.is_some_and( |end| end.line() == start.line() && end.column() == start.column() );
+
+        match fields.last_mut() {
+            Some( field ) if joined => field.text.push_str( &token.to_string() ),
+            _ => fields.push( Field{ text: token.to_string(), span } )
+        }
+
+        previous_end = Some( span.end() );
+    }
+
+    if fields.is_empty() {
+        return Err( ( "Expected a cron expression".to_owned(), Span::call_site() ) );
+    }
+
+    Ok( fields )
+}
+
+/// The contents of a plain or raw string literal
+fn unquote( literal: &str ) -> Option<String> {
+    let raw = literal.strip_prefix( 'r' ).map( |rest| rest.trim_matches( '#' ) );
+    let inner = raw.unwrap_or( literal ).strip_prefix( '"' )?.strip_suffix( '"' )?;
+
+    if raw.is_none() && inner.contains( '\\' ) {
+        return None;
+    }
+
+    Some( inner.to_owned() )
+}
+
+/// The span of the field the error points into, the expression was joined with single spaces
+fn field_at( fields: &[Field], error: &CronError ) -> Span {
+    let mut end = 0;
+
+    for field in fields {
+        end += field.text.len() + 1;
+
+        if error.span().start < end {
+            return field.span;
+        }
+    }
+
+    fields[ 0 ].span
+}
+
+fn compile_error( message: &str, span: Span ) -> TokenStream {
+    let mut literal = Literal::string( message );
+    literal.set_span( span );
+
+    let mut group = Group::new( Delimiter::Parenthesis, TokenStream::from( TokenTree::Literal( literal ) ) );
+    group.set_span( span );
+
+    let mut bang = Punct::new( '!', Spacing::Alone );
+    bang.set_span( span );
+
+    [
+        TokenTree::Ident( Ident::new( "compile_error", span ) ),
+        TokenTree::Punct( bang ),
+        TokenTree::Group( group ),
+    ].into_iter().collect()
+}
+
+fn schedule_code( parts: CronParts ) -> String {
+    format!(
+        "::croncron::CronSchedule::from( ::croncron::macro_support::CronParts {{ \
+            second: {}, minute: {}, hour: {}, day_of_month: {}, month: {}, day_of_week: {}, year: {}, nickname: {}, dialect: {}, day_matching: {}, time_zone: {}, dst_policy: {} \
+        }} )",
+        option_code( parts.second.as_ref().map( command_code ) ),
+        command_code( &parts.minute ),
+        command_code( &parts.hour ),
+        command_code( &parts.day_of_month ),
+        command_code( &parts.month ),
+        command_code( &parts.day_of_week ),
+        option_code( parts.year.as_ref().map( command_code ) ),
+        option_code( parts.nickname.as_ref().map( nickname_code ) ),
+        dialect_code( &parts.dialect ),
+        day_matching_code( &parts.day_matching ),
+        time_zone_code( &parts.time_zone ),
+        dst_policy_code( &parts.dst_policy ),
+    )
+}
+
+fn option_code( code: Option<String> ) -> String {
+    match code {
+        Some( code ) => format!( "::std::option::Option::Some( {code} )" ),
+        None => "::std::option::Option::None".to_owned()
+    }
+}
+
+fn command_code( command: &CronCommand ) -> String {
+    match command {
+        CronCommand::Asterisk => format!( "{COMMAND}::Asterisk" ),
+        CronCommand::NoSpecific => format!( "{COMMAND}::NoSpecific" ),
+        CronCommand::Number(n) => format!( "{COMMAND}::Number( {n} )" ),
+        CronCommand::Range( start, end ) => format!( "{COMMAND}::Range( {start}, {end} )" ),
+        CronCommand::Step( base, step ) => format!( "{COMMAND}::Step( {}, {step} )", step_base_code( base ) ),
+        CronCommand::W(n) => format!( "{COMMAND}::W( {n} )" ),
+        CronCommand::L(weekday) => format!( "{COMMAND}::L( {weekday} )" ),
+        CronCommand::LastDay(offset) => format!( "{COMMAND}::LastDay( {offset} )" ),
+        CronCommand::LastWeekday => format!( "{COMMAND}::LastWeekday" ),
+        CronCommand::Nth( weekday, n ) => format!( "{COMMAND}::Nth( {weekday}, {n} )" ),
+        CronCommand::List(items) => {
+            let items: Vec<String> = items.iter().map( command_code ).collect();
+
+            format!( "{COMMAND}::List( ::std::vec![ {} ] )", items.join( ", " ) )
+        }
+    }
+}
+
+fn step_base_code( base: &StepBase ) -> String {
+    match base {
+        StepBase::Multiple => format!( "{STEP_BASE}::Multiple" ),
+        StepBase::Asterisk => format!( "{STEP_BASE}::Asterisk" ),
+        StepBase::Start(start) => format!( "{STEP_BASE}::Start( {start} )" ),
+        StepBase::Range( start, end ) => format!( "{STEP_BASE}::Range( {start}, {end} )" ),
+    }
+}
+
+fn nickname_code( nickname: &CronNickname ) -> String {
+    let variant = match nickname {
+        CronNickname::Yearly => "Yearly",
+        CronNickname::Annually => "Annually",
+        CronNickname::Monthly => "Monthly",
+        CronNickname::Weekly => "Weekly",
+        CronNickname::Daily => "Daily",
+        CronNickname::Midnight => "Midnight",
+        CronNickname::Hourly => "Hourly",
+        CronNickname::Reboot => "Reboot",
+    };
+
+    format!( "::croncron::CronNickname::{variant}" )
+}
+
+fn dialect_code( dialect: &CronDialect ) -> String {
+    match dialect {
+        CronDialect::Standard => "::croncron::CronDialect::Standard".to_owned(),
+        CronDialect::Quartz => "::croncron::CronDialect::Quartz".to_owned(),
+    }
+}
+
+fn day_matching_code( day_matching: &CronDayMatching ) -> String {
+    match day_matching {
+        CronDayMatching::Either => "::croncron::CronDayMatching::Either".to_owned(),
+        CronDayMatching::Both => "::croncron::CronDayMatching::Both".to_owned(),
+    }
+}
+
+/// Expressions carry no time zone, so the schedules the macro parses are always in UTC
+fn time_zone_code( time_zone: &Tz ) -> String {
+    debug_assert_eq!( *time_zone, Tz::UTC );
+
+    "::croncron::Tz::UTC".to_owned()
+}
+
+fn dst_policy_code( dst_policy: &CronDstPolicy ) -> String {
+    let gap = match dst_policy.gap {
+        CronGapPolicy::Skip => "Skip",
+        CronGapPolicy::Shifted => "Shifted",
+    };
+
+    let overlap = match dst_policy.overlap {
+        CronOverlapPolicy::Once => "Once",
+        CronOverlapPolicy::Twice => "Twice",
+    };
+
+    format!( "::croncron::CronDstPolicy {{ gap: ::croncron::CronGapPolicy::{gap}, overlap: ::croncron::CronOverlapPolicy::{overlap} }}" )
+}
@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use chrono::{TimeZone, Utc};
+use croncron::CronSchedule;
+use croncron_macros::cron;
+
+macro_rules! same_as_runtime {
+    ($schedule:expr, $cron_string:literal) => {
+        assert_eq!( $schedule.to_string(), CronSchedule::from_str( $cron_string ).unwrap().to_string() )
+    };
+}
+
+#[test]
+fn should_expand_bare_tokens(){
+    same_as_runtime!( cron!( * * * * * ), "* * * * *" );
+    same_as_runtime!( cron!( */15 9-17 * * MON-FRI ), "*/15 9-17 * * MON-FRI" );
+    same_as_runtime!( cron!( 0,30 22-2 1,15 JAN,JUL * ), "0,30 22-2 1,15 JAN,JUL *" );
+    same_as_runtime!( cron!( 0 12 L-3 * * ), "0 12 L-3 * *" );
+    same_as_runtime!( cron!( 0 12 LW * * ), "0 12 LW * *" );
+    same_as_runtime!( cron!( 0 12 15W * * ), "0 12 15W * *" );
+    same_as_runtime!( cron!( 0 12 * * 5L ), "0 12 * * 5L" );
+    same_as_runtime!( cron!( 0 12 * * 2#2 ), "0 12 * * TUE#2" );
+    same_as_runtime!( cron!( "0 12 * * TUE#2" ), "0 12 * * 2#2" );
+    same_as_runtime!( cron!( 30 */5 * * * * ), "30 */5 * * * *" );
+    same_as_runtime!( cron!( 0 0 12 * * * 2030 ), "0 0 12 * * * 2030" );
+}
+
+#[test]
+fn should_expand_string_literal(){
+    same_as_runtime!( cron!( "0 0 1,15 * *" ), "0 0 1,15 * *" );
+    same_as_runtime!( cron!( r"5/10 * * * 0-7" ), "5/10 * * * 0-7" );
+}
+
+#[test]
+fn should_expand_nicknames(){
+    let schedule = cron!( @daily );
+
+    assert_eq!( schedule.to_string(), "@daily" );
+    assert!( cron!( "@reboot" ).is_reboot() );
+}
+
+#[test]
+fn should_match_like_runtime_schedule(){
+    let schedule = cron!( 30 9 * * MON-FRI );
+
+    assert!( schedule.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 9, 30, 0).unwrap() ) );
+    assert!( !schedule.check_date( &Utc.with_ymd_and_hms(2022, 12, 18, 9, 30, 0).unwrap() ) );
+    assert_eq!(
+        schedule.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 19, 5, 0, 0).unwrap() ),
+        Some( Utc.with_ymd_and_hms(2022, 12, 19, 9, 30, 0).unwrap() )
+    );
+}
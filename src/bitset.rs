@@ -0,0 +1,213 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+use crate::command::{CronCommand, StepBase};
+use crate::position::CronPosition;
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn compile( position: CronPosition, command: &str ) -> CronBitset {
+        CronBitset::compile( &position, &CronCommand::from_str( command ).unwrap() )
+    }
+
+    #[test]
+    fn should_set_bits_for_each_value(){
+        let bitset = compile( CronPosition::Minute, "*/15,7" );
+
+        assert!( bitset.contains( 0 ) && bitset.contains( 7 ) && bitset.contains( 45 ) );
+        assert!( !bitset.contains( 1 ) && !bitset.contains( 60 ) );
+
+        let bitset = compile( CronPosition::DayOfWeek, "5-1" );
+
+        assert!( bitset.contains( 6 ) && bitset.contains( 0 ) && bitset.contains( 1 ) );
+        assert!( !bitset.contains( 3 ) );
+    }
+
+    #[test]
+    fn should_find_next_and_previous_set_bits(){
+        let bitset = compile( CronPosition::Minute, "10,50" );
+
+        assert_eq!( bitset.next_from( 11 ), Some( 50 ) );
+        assert_eq!( bitset.next_from( 50 ), Some( 50 ) );
+        assert_eq!( bitset.next_from( 51 ), None );
+        assert_eq!( bitset.previous_from( 49 ), Some( 10 ) );
+        assert_eq!( bitset.previous_from( 9 ), None );
+
+        let bitset = compile( CronPosition::Year, "1975,2090" );
+
+        assert_eq!( bitset.next_from( 1976 ), Some( 2090 ) );
+        assert_eq!( bitset.previous_from( 2099 ), Some( 2090 ) );
+        assert_eq!( bitset.previous_from( 2089 ), Some( 1975 ) );
+    }
+
+    #[test]
+    fn should_keep_special_days_as_rules(){
+        let bitset = compile( CronPosition::DayOfMonth, "1,LW" );
+
+        assert!( bitset.contains( 1 ) && !bitset.contains( 30 ) );
+        assert!( bitset.matches_day( &NaiveDate::from_ymd_opt( 2023, 6, 30 ).unwrap() ) );
+        assert!( !bitset.matches_day( &NaiveDate::from_ymd_opt( 2023, 6, 29 ).unwrap() ) );
+    }
+
+    #[test]
+    fn should_write_the_shortest_command_for_the_values(){
+        let canonical = |position, command, restricted| compile( position, command ).canonical( &position, restricted ).to_string();
+
+        assert_eq!( canonical( CronPosition::Minute, "0-59", false ), "*" );
+        assert_eq!( canonical( CronPosition::Minute, "0,15,30,45", false ), "*/15" );
+        assert_eq!( canonical( CronPosition::Minute, "55,5/10", false ), "5/10" );
+        assert_eq!( canonical( CronPosition::Hour, "5,3,4,1,2,9,9,10", false ), "1-5,9,10" );
+        assert_eq!( canonical( CronPosition::DayOfWeek, "5-1", false ), "0,1,5,6" );
+        assert_eq!( canonical( CronPosition::DayOfMonth, "LW,15,L,1-31/2", true ), "1/2,L,LW" );
+    }
+}
+
+const WORDS: usize = 3;
+
+/// The values a field matches as bits counted from the position minimum, with the day rules that depend on the month kept aside
+#[derive(Debug, Clone)]
+pub struct CronBitset {
+    min: u32,
+    every: bool,
+    words: [u64; WORDS],
+    special_days: Vec<CronCommand>
+}
+
+impl CronBitset {
+    pub fn compile( position: &CronPosition, command: &CronCommand ) -> CronBitset {
+        let mut bitset = CronBitset {
+            min: position.get_min(),
+            every: matches!( command, CronCommand::Asterisk | CronCommand::NoSpecific ),
+            words: [0; WORDS],
+            special_days: vec![]
+        };
+
+        bitset.add( position, command );
+        bitset
+    }
+
+    fn add( &mut self, position: &CronPosition, command: &CronCommand ) {
+        match command {
+            CronCommand::List(items) => items.iter().for_each( |item| self.add( position, item ) ),
+            command if command.is_day_specific() => self.special_days.push( command.clone() ),
+            command => {
+                let ( min, max ) = ( position.get_min(), position.get_max() );
+
+                // Plain commands only look at the value, never the date
+                for value in ( min..=max ).filter( |value| command.is_valid( *value, min, max, &NaiveDateTime::MIN ) ) {
+                    let index = ( value - min ) as usize;
+                    self.words[ index / 64 ] |= 1 << ( index % 64 );
+                }
+            }
+        }
+    }
+
+    /// The shortest command for the same values and day rules, `restricted` rules out `*` and `*/n`
+    /// so that a day field still combines with the other as a restricted one
+    pub fn canonical( &self, position: &CronPosition, restricted: bool ) -> CronCommand {
+        let ( min, max ) = ( position.get_min(), position.get_max() );
+        let values: Vec<u32> = ( min..=max ).filter( |value| self.contains( *value ) ).collect();
+
+        if values.len() as u32 == max - min + 1 && !restricted {
+            return CronCommand::Asterisk;
+        }
+
+        let step = match values.as_slice() {
+            [ first, second, .. ] if values.len() > 2 => second - first,
+            _ => 0
+        };
+
+        // Values a fixed step apart up to the end of the field are the step from the first of them
+        let stepped = step > 1
+            && values.windows( 2 ).all( |pair| pair[ 1 ] - pair[ 0 ] == step )
+            && values[ values.len() - 1 ] + step > max;
+
+        let plain = match ( values.first(), stepped ) {
+            ( None, _ ) => None,
+            ( Some( first ), true ) if *first == min && !restricted => Some( CronCommand::Step( StepBase::Asterisk, step ) ),
+            ( Some( first ), true ) => Some( CronCommand::Step( StepBase::Start( *first ), step ) ),
+            ( Some( _ ), false ) => Some( CronCommand::from_values( &values ) )
+        };
+
+        let mut items = match plain {
+            Some( CronCommand::List( items ) ) => items,
+            Some( command ) => vec![ command ],
+            None => vec![]
+        };
+
+        let mut special_days: Vec<String> = self.special_days.iter().map( |day| day.to_string() ).collect();
+
+        special_days.sort();
+        special_days.dedup();
+
+        for day in special_days {
+            if let Some( command ) = self.special_days.iter().find( |command| command.to_string() == day ) {
+                items.push( command.clone() );
+            }
+        }
+
+        match items.len() {
+            1 => items.remove( 0 ),
+            _ => CronCommand::List( items )
+        }
+    }
+
+    pub fn contains( &self, value: u32 ) -> bool {
+        if self.every {
+            return true;
+        }
+
+        match value.checked_sub( self.min ).map( |index| index as usize ) {
+            Some( index ) if index < WORDS * 64 => self.words[ index / 64 ] >> ( index % 64 ) & 1 == 1,
+            _ => false
+        }
+    }
+
+    /// Whether one of the special day rules, such as `L` or `15W`, lands on the date
+    pub fn matches_day( &self, date: &NaiveDate ) -> bool {
+        self.special_days.iter().any( |day| day.day_in_month( date.year(), date.month() ) == Some( *date ) )
+    }
+
+    /// The smallest set value at or above the given one
+    pub fn next_from( &self, value: u32 ) -> Option<u32> {
+        let index = value.saturating_sub( self.min ) as usize;
+
+        if index >= WORDS * 64 {
+            return None;
+        }
+
+        let mut word = index / 64;
+        let mut bits = self.words[ word ] & ( u64::MAX << ( index % 64 ) );
+
+        loop {
+            if bits != 0 {
+                return Some( self.min + ( word * 64 ) as u32 + bits.trailing_zeros() );
+            }
+
+            word += 1;
+            bits = *self.words.get( word )?;
+        }
+    }
+
+    /// The largest set value at or below the given one
+    pub fn previous_from( &self, value: u32 ) -> Option<u32> {
+        let index = ( value.checked_sub( self.min )? as usize ).min( WORDS * 64 - 1 );
+
+        let mut word = index / 64;
+        let mut bits = self.words[ word ] & ( u64::MAX >> ( 63 - index % 64 ) );
+
+        loop {
+            if bits != 0 {
+                return Some( self.min + ( word * 64 ) as u32 + 63 - bits.leading_zeros() );
+            }
+
+            word = word.checked_sub( 1 )?;
+            bits = self.words[ word ];
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use crate::command::{CronCommand, DayOfWeek, Month, StepBase};
+use crate::errors::FieldError;
+use crate::macro_support::CronParts;
+use crate::{CronArg, CronDayMatching, CronDialect, CronDstPolicy, CronError, CronPosition, CronSchedule, Tz};
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn should_build_fields_from_typed_values(){
+        let c = CronSchedule::builder()
+            .minute( 0 )
+            .hours( 9..=17 )
+            .weekdays( [ DayOfWeek::Monday, DayOfWeek::Friday ] )
+            .build()
+            .unwrap();
+
+        assert_eq!( c.to_string(), "0 9-17 * * 1,5" );
+
+        let c = CronSchedule::builder()
+            .every_minutes( 15 )
+            .hours( [ 8, 12, 13, 14, 12 ] )
+            .months( [ Month::January, Month::July ] )
+            .build()
+            .unwrap();
+
+        assert_eq!( c.to_string(), "*/15 8,12-14 * 1,7 *" );
+        assert_eq!( CronSchedule::builder().build().unwrap().to_string(), "* * * * *" );
+    }
+
+    #[test]
+    fn should_build_special_days(){
+        let build = |builder: CronBuilder| builder.minute( 0 ).hour( 12 ).build().unwrap().to_string();
+
+        assert_eq!( build( CronSchedule::builder().last_day_of_month() ), "0 12 L * *" );
+        assert_eq!( build( CronSchedule::builder().last_weekday_of_month() ), "0 12 LW * *" );
+        assert_eq!( build( CronSchedule::builder().nearest_weekday( 15 ) ), "0 12 15W * *" );
+        assert_eq!( build( CronSchedule::builder().nth_weekday( DayOfWeek::Tuesday, 2 ) ), "0 12 * * 2#2" );
+        assert_eq!( build( CronSchedule::builder().last_of_month( DayOfWeek::Friday ) ), "0 12 * * 5L" );
+    }
+
+    #[test]
+    fn should_add_seconds_when_building_years(){
+        let c = CronSchedule::builder().minute( 30 ).hour( 6 ).day_of_month( 1 ).years( 2030..=2035 ).build().unwrap();
+
+        assert_eq!( c.to_string(), "0 30 6 1 * * 2030-2035" );
+    }
+
+    #[test]
+    fn should_reject_values_out_of_bounds(){
+        let error = CronSchedule::builder().minute( 60 ).hour( 24 ).build().unwrap_err();
+
+        assert_eq!( error, CronError::OutOfBounds { position: CronPosition::Minute, token: "60".to_owned(), span: 0..0, min: 0, max: 59 } );
+        assert_eq!( CronSchedule::builder().days_of_month( [ 1, 32 ] ).build().unwrap_err().token(), "32" );
+        assert_eq!( CronSchedule::builder().every_hours( 0 ).build().unwrap_err().bounds(), Some( ( 1, 23 ) ) );
+        assert_eq!( CronSchedule::builder().every_hours( 24 ).build().unwrap_err().bounds(), Some( ( 1, 23 ) ) );
+        assert_eq!( CronSchedule::builder().every_minutes( 60 ).build().unwrap_err().bounds(), Some( ( 1, 59 ) ) );
+        assert_eq!( CronSchedule::builder().every_seconds( 60 ).build().unwrap_err().bounds(), Some( ( 1, 59 ) ) );
+
+        let c = CronSchedule::builder().every_seconds( 59 ).every_hours( 23 ).every_months( 12 ).build().unwrap();
+
+        assert_eq!( CronSchedule::from_str( &c.to_string() ).unwrap().to_string(), c.to_string() );
+        assert_eq!( CronSchedule::builder().nth_weekday( DayOfWeek::Monday, 6 ).build().unwrap_err().bounds(), Some( ( 1, 5 ) ) );
+        assert_eq!( CronSchedule::builder().nearest_weekday( 32 ).build().unwrap_err().bounds(), Some( ( 1, 31 ) ) );
+        assert!( matches!( CronSchedule::builder().hours( [] ).build(), Err( CronError::InvalidArgument { position: CronPosition::Hour, .. } ) ) );
+        assert!( matches!( CronSchedule::builder().day_of_month( 30 ).month( Month::February ).build(), Err( CronError::Unsatisfiable { .. } ) ) );
+    }
+}
+
+/// Builds a schedule field by field from typed values, see `CronSchedule::builder`.
+///
+/// Fields that are not set match every value, seconds and years are only added when set.
+/// Values are checked against the bounds of their field when building, errors have empty spans as there is no expression
+#[derive(Debug, Clone, Default)]
+pub struct CronBuilder {
+    second: Option<CronCommand>,
+    minute: Option<CronCommand>,
+    hour: Option<CronCommand>,
+    day_of_month: Option<CronCommand>,
+    month: Option<CronCommand>,
+    day_of_week: Option<CronCommand>,
+    year: Option<CronCommand>,
+    error: Option<CronError>
+}
+
+impl CronBuilder {
+    pub fn second( self, second: u32 ) -> CronBuilder {
+        self.values( CronPosition::Second, [ second ] )
+    }
+
+    pub fn seconds( self, seconds: impl IntoIterator<Item = u32> ) -> CronBuilder {
+        self.values( CronPosition::Second, seconds )
+    }
+
+    pub fn every_seconds( self, step: u32 ) -> CronBuilder {
+        self.step( CronPosition::Second, step )
+    }
+
+    pub fn minute( self, minute: u32 ) -> CronBuilder {
+        self.values( CronPosition::Minute, [ minute ] )
+    }
+
+    pub fn minutes( self, minutes: impl IntoIterator<Item = u32> ) -> CronBuilder {
+        self.values( CronPosition::Minute, minutes )
+    }
+
+    pub fn every_minutes( self, step: u32 ) -> CronBuilder {
+        self.step( CronPosition::Minute, step )
+    }
+
+    pub fn hour( self, hour: u32 ) -> CronBuilder {
+        self.values( CronPosition::Hour, [ hour ] )
+    }
+
+    pub fn hours( self, hours: impl IntoIterator<Item = u32> ) -> CronBuilder {
+        self.values( CronPosition::Hour, hours )
+    }
+
+    pub fn every_hours( self, step: u32 ) -> CronBuilder {
+        self.step( CronPosition::Hour, step )
+    }
+
+    pub fn day_of_month( self, day: u32 ) -> CronBuilder {
+        self.values( CronPosition::DayOfMonth, [ day ] )
+    }
+
+    pub fn days_of_month( self, days: impl IntoIterator<Item = u32> ) -> CronBuilder {
+        self.values( CronPosition::DayOfMonth, days )
+    }
+
+    /// `L`
+    pub fn last_day_of_month( self ) -> CronBuilder {
+        self.set( CronPosition::DayOfMonth, Ok( CronCommand::LastDay( 0 ) ) )
+    }
+
+    /// `LW`
+    pub fn last_weekday_of_month( self ) -> CronBuilder {
+        self.set( CronPosition::DayOfMonth, Ok( CronCommand::LastWeekday ) )
+    }
+
+    /// The weekday nearest the given day of the month, `W`
+    pub fn nearest_weekday( self, day: u32 ) -> CronBuilder {
+        let command = validate( CronPosition::DayOfMonth, CronCommand::W( day ) );
+
+        self.set( CronPosition::DayOfMonth, command )
+    }
+
+    pub fn month( self, month: Month ) -> CronBuilder {
+        self.months( [ month ] )
+    }
+
+    pub fn months( self, months: impl IntoIterator<Item = Month> ) -> CronBuilder {
+        self.values( CronPosition::Month, months.into_iter().map( |month| month.index() ) )
+    }
+
+    pub fn every_months( self, step: u32 ) -> CronBuilder {
+        self.step( CronPosition::Month, step )
+    }
+
+    pub fn weekday( self, day: DayOfWeek ) -> CronBuilder {
+        self.weekdays( [ day ] )
+    }
+
+    pub fn weekdays( self, days: impl IntoIterator<Item = DayOfWeek> ) -> CronBuilder {
+        self.values( CronPosition::DayOfWeek, days.into_iter().map( |day| day.index() ) )
+    }
+
+    /// The nth given weekday of the month from 1 to 5, `#`
+    pub fn nth_weekday( self, day: DayOfWeek, n: u32 ) -> CronBuilder {
+        let command = validate( CronPosition::DayOfWeek, CronCommand::Nth( day.index(), n ) );
+
+        self.set( CronPosition::DayOfWeek, command )
+    }
+
+    /// The last given weekday of the month, `5L`
+    pub fn last_of_month( self, day: DayOfWeek ) -> CronBuilder {
+        self.set( CronPosition::DayOfWeek, Ok( CronCommand::L( day.index() ) ) )
+    }
+
+    pub fn year( self, year: u32 ) -> CronBuilder {
+        self.values( CronPosition::Year, [ year ] )
+    }
+
+    pub fn years( self, years: impl IntoIterator<Item = u32> ) -> CronBuilder {
+        self.values( CronPosition::Year, years )
+    }
+
+    /// The schedule, or the first value that was out of bounds. Seconds default to 0 when only years are set
+    pub fn build( self ) -> Result<CronSchedule, CronError> {
+        if let Some( error ) = self.error {
+            return Err( error );
+        }
+
+        let second = match ( self.second, &self.year ) {
+            ( None, Some( _ ) ) => Some( CronCommand::Number( 0 ) ),
+            ( second, _ ) => second
+        };
+
+        let schedule = CronSchedule::from( CronParts {
+            second,
+            minute: self.minute.unwrap_or( CronCommand::Asterisk ),
+            hour: self.hour.unwrap_or( CronCommand::Asterisk ),
+            day_of_month: self.day_of_month.unwrap_or( CronCommand::Asterisk ),
+            month: self.month.unwrap_or( CronCommand::Asterisk ),
+            day_of_week: self.day_of_week.unwrap_or( CronCommand::Asterisk ),
+            year: self.year,
+            nickname: None,
+            dialect: CronDialect::Standard,
+            day_matching: CronDayMatching::default(),
+            time_zone: Tz::UTC,
+            dst_policy: CronDstPolicy::default(),
+        });
+
+        if !schedule.is_satisfiable() {
+            let token = schedule.to_string();
+
+            return Err( CronError::Unsatisfiable { span: 0..token.len(), token } );
+        }
+
+        Ok( schedule )
+    }
+
+    /// Sorted values, with runs of three or more written as ranges
+    fn values( self, position: CronPosition, values: impl IntoIterator<Item = u32> ) -> CronBuilder {
+        let mut values: Vec<u32> = values.into_iter().collect();
+
+        values.sort_unstable();
+        values.dedup();
+
+        let command = match values.iter().find_map( |value| validate( position, CronCommand::Number( *value ) ).err() ) {
+            Some( error ) => Err( error ),
+            None if values.is_empty() => Err( CronError::InvalidArgument { position, token: String::new(), span: 0..0 } ),
+            None => Ok( CronCommand::from_values( &values ) )
+        };
+
+        self.set( position, command )
+    }
+
+    /// `*/n`
+    fn step( self, position: CronPosition, step: u32 ) -> CronBuilder {
+        let command = match step {
+            1 => Ok( CronCommand::Asterisk ),
+            step => validate( position, CronCommand::Step( StepBase::Asterisk, step ) )
+        };
+
+        self.set( position, command )
+    }
+
+    /// Sets the field, keeping the first error
+    fn set( mut self, position: CronPosition, command: Result<CronCommand, CronError> ) -> CronBuilder {
+        let command = match command {
+            Ok( command ) => Some( command ),
+            Err( error ) => {
+                self.error.get_or_insert( error );
+                return self;
+            }
+        };
+
+        match position {
+            CronPosition::Second => self.second = command,
+            CronPosition::Minute => self.minute = command,
+            CronPosition::Hour => self.hour = command,
+            CronPosition::DayOfMonth => self.day_of_month = command,
+            CronPosition::Month => self.month = command,
+            CronPosition::DayOfWeek => self.day_of_week = command,
+            CronPosition::Year => self.year = command,
+        }
+
+        self
+    }
+}
+
+/// The command when it is valid for the field, with the same bounds as the parser
+fn validate( position: CronPosition, command: CronCommand ) -> Result<CronCommand, CronError> {
+    match CronArg::validate( &position, &command ) {
+        Ok( () ) => Ok( command ),
+        Err( FieldError::OutOfBounds { value, min, max } ) => Err( CronError::OutOfBounds { position, token: value.to_string(), span: 0..0, min, max } ),
+        Err( _ ) => Err( CronError::InvalidArgument { position, token: command.to_string(), span: 0..0 } )
+    }
+}
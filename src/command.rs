@@ -1,102 +1,305 @@
 
-use std::{error::Error};
+use std::{fmt::Display, str::FromStr};
 
 
-use chrono::{DateTime, Utc, Datelike};
-use regex::Regex;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+
+use crate::errors::FieldError;
+use crate::parser::parse_field;
 
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use chrono::{DateTime, Utc};
 
     #[test]
     fn w_should_match_weekday(){
         let command = CronCommand::W( 5 );
-        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( command.is_valid( 5, &date ) );
+        assert!( command.is_valid( 5, 1, 31, &date ) );
     }
 
     #[test]
     fn w_should_not_match_sunday(){
         let command = CronCommand::W( 4 );
-        let date = "2022-12-4 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2022-12-4 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( !command.is_valid( 4, &date ) );
+        assert!( !command.is_valid( 4, 1, 31, &date ) );
     }
 
     #[test]
     fn w_should_match_next_monday(){
         let command = CronCommand::W( 4 );
-        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( command.is_valid( 5, &date ) );
+        assert!( command.is_valid( 5, 1, 31, &date ) );
     }
 
     #[test]
     fn w_should_not_match_saturday(){
         let command = CronCommand::W( 3 );
-        let date = "2022-12-3 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2022-12-3 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( !command.is_valid( 3, &date ) );
+        assert!( !command.is_valid( 3, 1, 31, &date ) );
     }
 
     #[test]
     fn w_should_match_prev_friday(){
         let command = CronCommand::W( 3 );
-        let date = "2022-12-2 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2022-12-2 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( command.is_valid( 2, &date ) );
+        assert!( command.is_valid( 2, 1, 31, &date ) );
     }
 
     #[test]
     fn w_should_match_next_monday_if_early_month(){
         let command = CronCommand::W( 1 );
-        let date = "2022-10-3 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2022-10-3 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( command.is_valid( 3, &date ) );
+        assert!( command.is_valid( 3, 1, 31, &date ) );
     }
 
     #[test]
     fn l_should_match_last_friday(){
         let command = CronCommand::L( 5 );
-        let date = "2023-01-27 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2023-01-27 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( command.is_valid( 5, &date ) );
+        assert!( command.is_valid( 5, 1, 31, &date ) );
     }
 
     #[test]
     fn l_should_not_match_first_friday(){
         let command = CronCommand::L( 5 );
-        let date = "2023-01-6 11:36:00Z".parse::<DateTime<Utc>>().unwrap();
+        let date = "2023-01-6 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
 
-        assert!( !command.is_valid( 5, &date ) );
+        assert!( !command.is_valid( 5, 1, 31, &date ) );
+    }
+
+    #[test]
+    fn list_should_parse_mixed_elements(){
+        let command = CronCommand::from_str( "1,5-10,*/20" ).unwrap();
+
+        match command {
+            CronCommand::List( ref items ) => assert_eq!( items.len(), 3 ),
+            _ => panic!( "Expected a list" )
+        }
+    }
+
+    #[test]
+    fn list_should_match_any_element(){
+        let command = CronCommand::from_str( "0,15,30-32" ).unwrap();
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 15, 0, 59, &date ) );
+        assert!( command.is_valid( 31, 0, 59, &date ) );
+        assert!( !command.is_valid( 20, 0, 59, &date ) );
     }
-}
 
+    #[test]
+    fn step_should_parse_each_base(){
+        assert!( matches!( CronCommand::from_str( "/6" ).unwrap(), CronCommand::Step( StepBase::Multiple, 6 ) ) );
+        assert!( matches!( CronCommand::from_str( "*/15" ).unwrap(), CronCommand::Step( StepBase::Asterisk, 15 ) ) );
+        assert!( matches!( CronCommand::from_str( "5/15" ).unwrap(), CronCommand::Step( StepBase::Start( 5 ), 15 ) ) );
+        assert!( matches!( CronCommand::from_str( "10-50/5" ).unwrap(), CronCommand::Step( StepBase::Range( 10, 50 ), 5 ) ) );
+        assert!( CronCommand::from_str( "*/" ).is_err() );
+        assert!( CronCommand::from_str( "a-b/5" ).is_err() );
+    }
+
+    #[test]
+    fn step_should_start_from_position_minimum(){
+        let command = CronCommand::from_str( "*/10" ).unwrap();
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 0, 0, 59, &date ) );
+        assert!( command.is_valid( 1, 1, 31, &date ) );
+        assert!( command.is_valid( 21, 1, 31, &date ) );
+        assert!( !command.is_valid( 20, 1, 31, &date ) );
+    }
+
+    #[test]
+    fn step_should_only_match_within_range(){
+        let command = CronCommand::from_str( "10-30/5" ).unwrap();
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 10, 0, 59, &date ) );
+        assert!( command.is_valid( 25, 0, 59, &date ) );
+        assert!( !command.is_valid( 5, 0, 59, &date ) );
+        assert!( !command.is_valid( 35, 0, 59, &date ) );
+        assert!( !command.is_valid( 12, 0, 59, &date ) );
+    }
+
+    #[test]
+    fn day_of_week_should_parse_names_in_any_case(){
+        assert_eq!( DayOfWeek::from_name( "MON" ).unwrap().index(), 1 );
+        assert_eq!( DayOfWeek::from_name( "friday" ).unwrap().index(), 5 );
+        assert_eq!( DayOfWeek::from_name( "Sun" ).unwrap().index(), 0 );
+        assert!( DayOfWeek::from_name( "Funday" ).is_none() );
+        assert!( DayOfWeek::from_name( "Mo" ).is_none() );
+    }
+
+    #[test]
+    fn month_should_parse_names_in_any_case(){
+        assert_eq!( Month::from_name( "JAN" ).unwrap().index(), 1 );
+        assert_eq!( Month::from_name( "july" ).unwrap().index(), 7 );
+        assert_eq!( Month::from_name( "Dec" ).unwrap().index(), 12 );
+        assert!( Month::from_name( "Smarch" ).is_none() );
+    }
+
+    #[test]
+    fn nth_should_match_second_tuesday(){
+        let command = CronCommand::from_str( "2#2" ).unwrap();
+        let date = "2022-12-13 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 2, 0, 6, &date ) );
+
+        let date = "2022-12-6 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( !command.is_valid( 2, 0, 6, &date ) );
+    }
+
+    #[test]
+    fn nth_should_skip_months_without_fifth_occurrence(){
+        let command = CronCommand::Nth( 1, 5 );
+
+        assert_eq!( command.day_in_month( 2022, 11 ), None );
+        assert_eq!( command.day_in_month( 2022, 12 ), None );
+        assert_eq!( command.day_in_month( 2023, 1 ), NaiveDate::from_ymd_opt( 2023, 1, 30 ) );
+    }
+
+    #[test]
+    fn l_should_match_last_friday_of_december(){
+        let command = CronCommand::L( 5 );
+        let date = "2022-12-30 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 5, 0, 6, &date ) );
+    }
+
+    #[test]
+    fn last_day_should_account_for_leap_years(){
+        assert_eq!( CronCommand::LastDay( 0 ).day_in_month( 2024, 2 ), NaiveDate::from_ymd_opt( 2024, 2, 29 ) );
+        assert_eq!( CronCommand::LastDay( 0 ).day_in_month( 2023, 2 ), NaiveDate::from_ymd_opt( 2023, 2, 28 ) );
+        assert_eq!( CronCommand::LastDay( 3 ).day_in_month( 2023, 4 ), NaiveDate::from_ymd_opt( 2023, 4, 27 ) );
+        assert_eq!( CronCommand::LastDay( 29 ).day_in_month( 2023, 2 ), None );
+    }
 
-fn is_range( arg: &str ) -> bool {
-    Regex::new( r"^\d+-\d+$" ).unwrap().is_match( arg )
+    #[test]
+    fn last_weekday_should_skip_weekend(){
+        // April 2023 ends on a Sunday, September 2023 on a Saturday
+        assert_eq!( CronCommand::LastWeekday.day_in_month( 2023, 4 ), NaiveDate::from_ymd_opt( 2023, 4, 28 ) );
+        assert_eq!( CronCommand::LastWeekday.day_in_month( 2023, 9 ), NaiveDate::from_ymd_opt( 2023, 9, 29 ) );
+        assert_eq!( CronCommand::LastWeekday.day_in_month( 2023, 5 ), NaiveDate::from_ymd_opt( 2023, 5, 31 ) );
+    }
+
+    #[test]
+    fn w_should_not_leave_month(){
+        // July 2023 starts on a Saturday, December 2023 ends on a Sunday
+        assert_eq!( CronCommand::W( 1 ).day_in_month( 2023, 7 ), NaiveDate::from_ymd_opt( 2023, 7, 3 ) );
+        assert_eq!( CronCommand::W( 31 ).day_in_month( 2023, 12 ), NaiveDate::from_ymd_opt( 2023, 12, 29 ) );
+        assert_eq!( CronCommand::W( 31 ).day_in_month( 2023, 11 ), None );
+    }
+
+    #[test]
+    fn should_parse_l_family(){
+        assert!( matches!( CronCommand::from_str( "L" ).unwrap(), CronCommand::LastDay( 0 ) ) );
+        assert!( matches!( CronCommand::from_str( "L-3" ).unwrap(), CronCommand::LastDay( 3 ) ) );
+        assert!( matches!( CronCommand::from_str( "LW" ).unwrap(), CronCommand::LastWeekday ) );
+        assert!( matches!( CronCommand::from_str( "5L" ).unwrap(), CronCommand::L( 5 ) ) );
+        assert!( CronCommand::from_str( "L-" ).is_err() );
+    }
+
+    #[test]
+    fn range_should_wrap_past_maximum(){
+        let command = CronCommand::Range( 22, 2 );
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 23, 0, 23, &date ) );
+        assert!( command.is_valid( 0, 0, 23, &date ) );
+        assert!( command.is_valid( 2, 0, 23, &date ) );
+        assert!( !command.is_valid( 3, 0, 23, &date ) );
+        assert!( !command.is_valid( 21, 0, 23, &date ) );
+    }
+
+    #[test]
+    fn step_should_wrap_past_maximum(){
+        let command = CronCommand::from_str( "20-4/3" ).unwrap();
+        let date = "2022-12-5 11:36:00Z".parse::<DateTime<Utc>>().unwrap().naive_utc();
+
+        assert!( command.is_valid( 23, 0, 23, &date ) );
+        assert!( command.is_valid( 2, 0, 23, &date ) );
+        assert!( !command.is_valid( 0, 0, 23, &date ) );
+    }
+
+    #[test]
+    fn should_normalize_seven_to_sunday(){
+        assert!( matches!( CronCommand::Number( 7 ).normalize_sunday(), CronCommand::Number( 0 ) ) );
+        assert!( matches!( CronCommand::Range( 5, 7 ).normalize_sunday(), CronCommand::Range( 5, 0 ) ) );
+        assert!( matches!( CronCommand::Range( 0, 7 ).normalize_sunday(), CronCommand::Range( 0, 6 ) ) );
+        assert!( matches!( CronCommand::Nth( 7, 1 ).normalize_sunday(), CronCommand::Nth( 0, 1 ) ) );
+        assert!( matches!( CronCommand::Step( StepBase::Start( 7 ), 2 ).normalize_sunday(), CronCommand::Number( 0 ) ) );
+    }
+
+    #[test]
+    fn should_map_weekdays_to_quartz_numbers(){
+        let command = CronCommand::from_str( "1-7,2#2,6L" ).unwrap();
+        let standard = command.map_weekdays( &|n| ( 1..=7 ).contains( &n ).then( || n - 1 ) ).unwrap();
+
+        assert_eq!( standard.to_string(), "0-6,1#2,5L" );
+        assert_eq!( standard.map_weekdays( &|n| Some( n + 1 ) ).unwrap().to_string(), "1-7,2#2,6L" );
+        assert!( CronCommand::from_str( "0-3" ).unwrap().map_weekdays( &|n| ( 1..=7 ).contains( &n ).then( || n - 1 ) ).is_none() );
+    }
+
+    #[test]
+    fn list_should_reject_empty_element(){
+        assert!( CronCommand::from_str( "1,,2" ).is_err() );
+        assert!( CronCommand::from_str( "1," ).is_err() );
+    }
 }
 
-fn is_day( arg: &str ) -> bool {
-    Regex::new( r"(?i)Sunday|Monday|Tuesday|Wednesday|Thursday|Friday|Saturday" ).unwrap().is_match( arg )
+
+/// The number of days in a month, accounting for leap years
+pub fn days_in_month( year: i32, month: u32 ) -> u32 {
+    let ( next_year, next_month ) = if month == 12 { ( year + 1, 1 ) } else { ( year, month + 1 ) };
+
+    NaiveDate::from_ymd_opt( next_year, next_month, 1 )
+        .and_then( |first| first.pred_opt() )
+        .map_or( 31, |last| last.day() )
 }
 
-fn is_interval( arg: &str ) -> bool {
-    Regex::new( r"/\d+" ).unwrap().is_match( arg )
+/// The date of the nth given weekday in a month, `None` when the month is too short
+fn nth_weekday_of_month( year: i32, month: u32, weekday: u32, n: u32 ) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt( year, month, 1 )?;
+    let offset = ( weekday + 7 - first.weekday().num_days_from_sunday() ) % 7;
+
+    NaiveDate::from_ymd_opt( year, month, 1 + offset + ( n - 1 ) * 7 )
 }
 
-fn is_w( arg: &str ) -> bool {
-    Regex::new( r"\d+W" ).unwrap().is_match( arg )
+fn last_weekday_of_month( year: i32, month: u32, weekday: u32 ) -> Option<NaiveDate> {
+    let last = NaiveDate::from_ymd_opt( year, month, days_in_month( year, month ) )?;
+    let offset = ( last.weekday().num_days_from_sunday() + 7 - weekday ) % 7;
+
+    NaiveDate::from_ymd_opt( year, month, last.day() - offset )
 }
 
-fn is_l( arg: &str ) -> bool {
-    Regex::new( r"\d+L" ).unwrap().is_match( arg )
+/// The weekday closest to the given day without leaving the month
+fn nearest_weekday( year: i32, month: u32, day: u32 ) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt( year, month, day )?;
+    let last = days_in_month( year, month );
+
+    let nearest = match date.weekday() {
+        Weekday::Sat if day == 1 => day + 2,
+        Weekday::Sat => day - 1,
+        Weekday::Sun if day == last => day - 2,
+        Weekday::Sun => day + 1,
+        _ => day
+    };
+
+    NaiveDate::from_ymd_opt( year, month, nearest )
 }
 
-#[derive(Debug)]
+/// A day of the week, numbered from 0 for Sunday
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DayOfWeek {
     Sunday,
     Monday,
@@ -108,28 +311,31 @@ pub enum DayOfWeek {
 }
 
 impl DayOfWeek {
-    pub fn from_str( day: &str ) -> DayOfWeek {
-        match day {
-            "Sunday" => DayOfWeek::Sunday,
-            "Monday" => DayOfWeek::Monday,
-            "Tuesday" => DayOfWeek::Tuesday,
-            "Wednesday" => DayOfWeek::Wednesday,
-            "Thursday" => DayOfWeek::Thursday,
-            "Friday" => DayOfWeek::Friday,
-            "Saturday" => DayOfWeek::Saturday,
-            _ => panic!( "Invalid day of week!" )
+    /// Accepts the full name or the three letter abbreviation in any case
+    pub fn from_name( name: &str ) -> Option<DayOfWeek> {
+        match name.to_ascii_lowercase().as_str() {
+            "sun" | "sunday" => Some(DayOfWeek::Sunday),
+            "mon" | "monday" => Some(DayOfWeek::Monday),
+            "tue" | "tuesday" => Some(DayOfWeek::Tuesday),
+            "wed" | "wednesday" => Some(DayOfWeek::Wednesday),
+            "thu" | "thursday" => Some(DayOfWeek::Thursday),
+            "fri" | "friday" => Some(DayOfWeek::Friday),
+            "sat" | "saturday" => Some(DayOfWeek::Saturday),
+            _ => None
         }
     }
 
-    pub fn to_string(&self) -> String {
-        match self {
-            DayOfWeek::Sunday => "Sunday".to_owned(),
-            DayOfWeek::Monday => "Monday".to_owned(),
-            DayOfWeek::Tuesday => "Tuesday".to_owned(),
-            DayOfWeek::Wednesday => "Wednesday".to_owned(),
-            DayOfWeek::Thursday => "Thursday".to_owned(),
-            DayOfWeek::Friday => "Friday".to_owned(),
-            DayOfWeek::Saturday => "Saturday".to_owned(),
+    /// The weekday for a number from 0 for Sunday to 6 for Saturday
+    pub fn from_index( index: u32 ) -> Option<DayOfWeek> {
+        match index {
+            0 => Some(DayOfWeek::Sunday),
+            1 => Some(DayOfWeek::Monday),
+            2 => Some(DayOfWeek::Tuesday),
+            3 => Some(DayOfWeek::Wednesday),
+            4 => Some(DayOfWeek::Thursday),
+            5 => Some(DayOfWeek::Friday),
+            6 => Some(DayOfWeek::Saturday),
+            _ => None
         }
     }
 
@@ -146,145 +352,336 @@ impl DayOfWeek {
     }
 }
 
-#[derive(Debug)]
-pub enum CronCommand {
-    Asterisk,
-    Number(u32),
-    Range(u32, u32),
-    DayOfWeek(DayOfWeek),
-    Interval(u32),
-    W(u32),
-    L(u32)
+impl Display for DayOfWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            DayOfWeek::Sunday => "Sunday",
+            DayOfWeek::Monday => "Monday",
+            DayOfWeek::Tuesday => "Tuesday",
+            DayOfWeek::Wednesday => "Wednesday",
+            DayOfWeek::Thursday => "Thursday",
+            DayOfWeek::Friday => "Friday",
+            DayOfWeek::Saturday => "Saturday",
+        };
+
+        write!( f, "{display}" )
+    }
 }
 
-impl CronCommand {
-    pub fn from_str( val: &str ) -> Result<CronCommand, Box<dyn Error>> {
-        match val {
-            l_str if is_l( l_str ) => {
-                let num: u32 = l_str.replace(r"L", "" ).parse()?;
+/// A month of the year, numbered from 1 for January
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December
+}
 
-                Ok(CronCommand::L( num ))
-            },
-            w_str if is_w( w_str ) => {
-                let num: u32 = w_str.replace(r"W", "" ).parse()?;
+impl Month {
+    /// Accepts the full name or the three letter abbreviation in any case
+    pub fn from_name( name: &str ) -> Option<Month> {
+        match name.to_ascii_lowercase().as_str() {
+            "jan" | "january" => Some(Month::January),
+            "feb" | "february" => Some(Month::February),
+            "mar" | "march" => Some(Month::March),
+            "apr" | "april" => Some(Month::April),
+            "may" => Some(Month::May),
+            "jun" | "june" => Some(Month::June),
+            "jul" | "july" => Some(Month::July),
+            "aug" | "august" => Some(Month::August),
+            "sep" | "september" => Some(Month::September),
+            "oct" | "october" => Some(Month::October),
+            "nov" | "november" => Some(Month::November),
+            "dec" | "december" => Some(Month::December),
+            _ => None
+        }
+    }
 
-                Ok(CronCommand::W( num ))
-            },
-            interval_str if is_interval( interval_str ) => {
-                let num: u32 = interval_str.replace("/", "" ).parse()?;
-                
-                Ok(CronCommand::Interval( num ))
-            },
-            day_str if is_day( day_str ) => {
-                Ok(CronCommand::DayOfWeek( DayOfWeek::from_str( day_str ) ))
-            },
-            range_str if is_range( range_str ) => {
-                let parts : Vec<&str> = range_str.split( '-' ).collect();
+    /// The month for a number from 1 for January to 12 for December
+    pub fn from_index( index: u32 ) -> Option<Month> {
+        match index {
+            1 => Some(Month::January),
+            2 => Some(Month::February),
+            3 => Some(Month::March),
+            4 => Some(Month::April),
+            5 => Some(Month::May),
+            6 => Some(Month::June),
+            7 => Some(Month::July),
+            8 => Some(Month::August),
+            9 => Some(Month::September),
+            10 => Some(Month::October),
+            11 => Some(Month::November),
+            12 => Some(Month::December),
+            _ => None
+        }
+    }
 
-                let min = u32::from_str_radix( parts[0], 10 )?;
-                let max = u32::from_str_radix( parts[1], 10 )?;
+    pub fn index(&self) -> u32 {
+        match self {
+            Month::January => 1,
+            Month::February => 2,
+            Month::March => 3,
+            Month::April => 4,
+            Month::May => 5,
+            Month::June => 6,
+            Month::July => 7,
+            Month::August => 8,
+            Month::September => 9,
+            Month::October => 10,
+            Month::November => 11,
+            Month::December => 12,
+        }
+    }
+}
 
-                Ok(CronCommand::Range(min, max))
-            },
-            "*" => Ok(CronCommand::Asterisk),
-            v => {
-                let num = u32::from_str_radix( v, 10 )?;
+impl Display for Month {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            Month::January => "January",
+            Month::February => "February",
+            Month::March => "March",
+            Month::April => "April",
+            Month::May => "May",
+            Month::June => "June",
+            Month::July => "July",
+            Month::August => "August",
+            Month::September => "September",
+            Month::October => "October",
+            Month::November => "November",
+            Month::December => "December",
+        };
+
+        write!( f, "{display}" )
+    }
+}
 
-                Ok(CronCommand::Number( num ))
-            }
+/// The values a step walks over before the step is applied
+#[derive(Debug, Clone)]
+pub enum StepBase {
+    /// `/n`, every multiple of n
+    Multiple,
+    /// `*/n`, every n starting from the position minimum
+    Asterisk,
+    /// `a/n`, every n starting from a up to the position maximum
+    Start(u32),
+    /// `a-b/n`, every n starting from a up to b
+    Range(u32, u32)
+}
+
+impl StepBase {
+    pub fn bounds( &self, min: u32, max: u32 ) -> ( u32, u32 ) {
+        match self {
+            StepBase::Multiple => ( 0, max ),
+            StepBase::Asterisk => ( min, max ),
+            StepBase::Start(start) => ( *start, max ),
+            StepBase::Range( start, end ) => ( *start, *end ),
         }
     }
+}
 
-    pub fn to_string( &self ) -> String {
+impl Display for StepBase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CronCommand::Asterisk => "*".to_owned(),
-            CronCommand::Number(n) => n.to_string() ,
-            CronCommand::Range( min, max ) => format!( "{min} to {max}" ),
-            CronCommand::DayOfWeek(d) => d.to_string(),
-            CronCommand::Interval(i) => format!( "/{i}" ),
-            CronCommand::W(n) => format!( "{n}W" ),
-            CronCommand::L(n) => format!( "{n}L" ),
+            StepBase::Multiple => Ok(()),
+            StepBase::Asterisk => write!( f, "*" ),
+            StepBase::Start(start) => write!( f, "{start}" ),
+            StepBase::Range( start, end ) => write!( f, "{start}-{end}" ),
         }
     }
+}
+
+#[derive(Debug, Clone)]
+pub enum CronCommand {
+    Asterisk,
+    NoSpecific,
+    Number(u32),
+    Range(u32, u32),
+    Step(StepBase, u32),
+    W(u32),
+    L(u32),
+    LastDay(u32),
+    LastWeekday,
+    Nth(u32, u32),
+    List(Vec<CronCommand>)
+}
+
+impl FromStr for CronCommand {
+    type Err = FieldError;
+
+    /// Parses a field without month or weekday names, see `parse_field`
+    fn from_str( val: &str ) -> Result<CronCommand, FieldError> {
+        parse_field( val, &|_| None )
+    }
+}
 
-    pub fn is_valid( &self, current: u32, date: &DateTime<Utc> ) -> bool {
+impl CronCommand {
+    pub fn is_valid( &self, current: u32, min: u32, max: u32, date: &NaiveDateTime ) -> bool {
         match self {
-            CronCommand::Asterisk => true,
+            CronCommand::Asterisk | CronCommand::NoSpecific => true,
             CronCommand::Number(n) => current == *n,
-            CronCommand::Range( min, max ) => current <= *max && current >= *min,
-            CronCommand::DayOfWeek(day) => date.weekday().num_days_from_sunday() == day.index(),
-            CronCommand::Interval(i) => (current % *i )== 0,
-            CronCommand::W(n) => {
-                if current == *n && date.weekday().num_days_from_monday() < 5 {
-                    true
-                }
-                else if date.weekday().num_days_from_sunday() == 1 && ( date.day() - 1 ) == *n {
-                    true 
-                }
-                else if date.weekday().num_days_from_sunday() == 5 && ( date.day() + 1 ) == *n {
-                    true 
-                }
-                else if *n == 1 && date.day() == 3 {
-                    true 
+            CronCommand::Range( start, end ) if start > end => current >= *start || current <= *end,
+            CronCommand::Range( start, end ) => current <= *end && current >= *start,
+            CronCommand::Step( base, step ) => {
+                let ( start, end ) = base.bounds( min, max );
+
+                if start > end {
+                    // Wrapping ranges carry on from the position minimum once they pass the maximum
+                    let index = if current >= start { current - start } else { current + ( max - min + 1 ) - start };
+
+                    ( current >= start || current <= end ) && index.is_multiple_of( *step )
                 }
                 else {
-                    false
+                    current >= start && current <= end && ( current - start ).is_multiple_of( *step )
                 }
             },
-            CronCommand::L(n) => {
-                let next_week = date.checked_add_days(chrono::Days::new(7) ).unwrap();
-                if current == *n && next_week.month() > date.month() {
-                    true
-                }
-                else {
-                    false
-                }
+            CronCommand::W(_)
+            | CronCommand::L(_)
+            | CronCommand::LastDay(_)
+            | CronCommand::LastWeekday
+            | CronCommand::Nth(..) => self.day_in_month( date.year(), date.month() ) == Some( date.date() ),
+            CronCommand::List(items) => items.iter().any( |item| item.is_valid( current, min, max, date ) )
+        }
+    }
+
+    /// Rewrites the alternative Sunday `7` as `0`, for the day of week position
+    pub fn normalize_sunday( self ) -> CronCommand {
+        let sunday = |n: u32| if n == 7 { 0 } else { n };
+
+        match self {
+            CronCommand::Number(n) => CronCommand::Number( sunday( n ) ),
+            CronCommand::Range( 0, 7 ) => CronCommand::Range( 0, 6 ),
+            CronCommand::Range( start, end ) => CronCommand::Range( sunday( start ), sunday( end ) ),
+            // Stepping from the last weekday goes no further than Sunday itself
+            CronCommand::Step( StepBase::Start(7), _ ) => CronCommand::Number( 0 ),
+            CronCommand::Step( StepBase::Range( 0, 7 ), step ) => CronCommand::Step( StepBase::Range( 0, 6 ), step ),
+            CronCommand::Step( StepBase::Range( start, end ), step ) => CronCommand::Step( StepBase::Range( sunday( start ), sunday( end ) ), step ),
+            CronCommand::L(weekday) => CronCommand::L( sunday( weekday ) ),
+            CronCommand::Nth( weekday, n ) => CronCommand::Nth( sunday( weekday ), n ),
+            CronCommand::List(items) => CronCommand::List( items.into_iter().map( CronCommand::normalize_sunday ).collect() ),
+            command => command
+        }
+    }
+
+    /// A number, a range or a list of both from sorted values without repeats, runs of three or more become ranges
+    pub fn from_values( values: &[u32] ) -> CronCommand {
+        let mut items = vec![];
+        let mut start = 0;
+
+        while start < values.len() {
+            let mut end = start;
+
+            while end + 1 < values.len() && values[ end + 1 ] == values[ end ] + 1 {
+                end += 1;
+            }
+
+            match end - start {
+                0 => items.push( CronCommand::Number( values[ start ] ) ),
+                1 => items.extend( [ CronCommand::Number( values[ start ] ), CronCommand::Number( values[ end ] ) ] ),
+                _ => items.push( CronCommand::Range( values[ start ], values[ end ] ) )
             }
+
+            start = end + 1;
+        }
+
+        match items.len() {
+            1 => items.remove( 0 ),
+            _ => CronCommand::List( items )
         }
     }
 
-    pub fn get_next_value( &self, current: u32, min: u32, max: u32 ) -> u32 {
+    /// Applies `f` to every weekday number in the command, `None` when `f` rejects one of them
+    pub fn map_weekdays( &self, f: &dyn Fn( u32 ) -> Option<u32> ) -> Option<CronCommand> {
+        let command = match self {
+            CronCommand::Number(n) => CronCommand::Number( f( *n )? ),
+            CronCommand::Range( start, end ) => CronCommand::Range( f( *start )?, f( *end )? ),
+            CronCommand::Step( StepBase::Start(start), step ) => CronCommand::Step( StepBase::Start( f( *start )? ), *step ),
+            CronCommand::Step( StepBase::Range( start, end ), step ) => CronCommand::Step( StepBase::Range( f( *start )?, f( *end )? ), *step ),
+            CronCommand::L(weekday) => CronCommand::L( f( *weekday )? ),
+            CronCommand::Nth( weekday, n ) => CronCommand::Nth( f( *weekday )?, *n ),
+            CronCommand::List(items) => CronCommand::List(
+                items.iter().map( |item| item.map_weekdays( f ) ).collect::<Option<Vec<CronCommand>>>()?
+            ),
+            command => command.clone()
+        };
+
+        Some( command )
+    }
+
+    /// Whether the field starts with `*` or is `?`, Vixie cron only ORs the day fields when neither does
+    pub fn is_unrestricted( &self ) -> bool {
+        matches!( self, CronCommand::Asterisk | CronCommand::NoSpecific | CronCommand::Step( StepBase::Asterisk, _ ) )
+    }
+
+    /// Whether the command picks specific days of the month rather than values of its position,
+    /// either directly or as part of a list
+    pub fn is_day_specific( &self ) -> bool {
         match self {
-            CronCommand::Asterisk => {
-                if current == max {
-                    min
-                }
-                else {
-                    current + 1
-                }
-            },
-            CronCommand::Number(num) => *num,
-            CronCommand::Range(min, max) => {
-                if current >= *max || current < *min {
-                    *min
-                }
-                else {
-                    current + 1
-                }
-            },
-            CronCommand::DayOfWeek(_) => todo!(),
-            CronCommand::Interval(i) => {
-                if *i > current {
-                    return *i;
-                }
+            CronCommand::W(_)
+            | CronCommand::L(_)
+            | CronCommand::LastDay(_)
+            | CronCommand::LastWeekday
+            | CronCommand::Nth(..) => true,
+            CronCommand::List(items) => items.iter().any( |item| item.is_day_specific() ),
+            _ => false
+        }
+    }
 
-                let m = current / i;
-                let next = if m == 0 {
-                    current + m
-                }
-                else {
-                    current + i
-                };
+    /// The day a day specific command picks in the given month, if the month has one
+    pub fn day_in_month( &self, year: i32, month: u32 ) -> Option<NaiveDate> {
+        match self {
+            CronCommand::W(n) => nearest_weekday( year, month, *n ),
+            CronCommand::L(weekday) => last_weekday_of_month( year, month, *weekday ),
+            CronCommand::LastDay(offset) => {
+                let last = days_in_month( year, month );
 
-                if next < max {
-                    next
+                if *offset >= last {
+                    return None;
                 }
-                else {
-                    *i
+
+                NaiveDate::from_ymd_opt( year, month, last - offset )
+            },
+            CronCommand::LastWeekday => {
+                let last = NaiveDate::from_ymd_opt( year, month, days_in_month( year, month ) )?;
+
+                match last.weekday() {
+                    Weekday::Sat => last.pred_opt(),
+                    Weekday::Sun => last.pred_opt()?.pred_opt(),
+                    _ => Some( last )
                 }
             },
-            CronCommand::W(n) => todo!(),
-            CronCommand::L(n) => todo!()
+            CronCommand::Nth( weekday, n ) => nth_weekday_of_month( year, month, *weekday, *n ),
+            _ => None
+        }
+    }
+}
+
+impl Display for CronCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CronCommand::Asterisk => write!( f, "*" ),
+            CronCommand::NoSpecific => write!( f, "?" ),
+            CronCommand::Number(n) => write!( f, "{n}" ),
+            CronCommand::Range( min, max ) => write!( f, "{min}-{max}" ),
+            CronCommand::Step( base, step ) => write!( f, "{base}/{step}" ),
+            CronCommand::W(n) => write!( f, "{n}W" ),
+            CronCommand::L(n) => write!( f, "{n}L" ),
+            CronCommand::LastDay(0) => write!( f, "L" ),
+            CronCommand::LastDay(offset) => write!( f, "L-{offset}" ),
+            CronCommand::LastWeekday => write!( f, "LW" ),
+            CronCommand::Nth( weekday, n ) => write!( f, "{weekday}#{n}" ),
+            CronCommand::List(items) => {
+                let parts: Vec<String> = items.iter().map( |item| item.to_string() ).collect();
+
+                write!( f, "{}", parts.join( "," ) )
+            }
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+/// How the day of month and day of week fields combine when both are restricted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CronDayMatching {
+    /// Vixie style OR, `0 0 1,15 * MON` runs on the 1st, the 15th and every Monday
+    #[default]
+    Either,
+    /// AND, a day has to match both fields
+    Both
+}
+
+impl Display for CronDayMatching {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            CronDayMatching::Either => "Either",
+            CronDayMatching::Both => "Both",
+        };
+
+        write!( f, "{display}" )
+    }
+}
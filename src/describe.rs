@@ -0,0 +1,168 @@
+use chrono::NaiveDateTime;
+
+use crate::command::{CronCommand, StepBase};
+use crate::{CronLocale, CronPosition, CronSchedule};
+
+/// Describes a schedule in the words and word order of the locale
+pub fn describe( schedule: &CronSchedule, locale: &dyn CronLocale ) -> String {
+    if schedule.is_reboot() {
+        return locale.at_startup();
+    }
+
+    let time = time_phrase( schedule, locale );
+    let days = day_phrase( schedule, locale );
+    let months = date_phrase( CronPosition::Month, &schedule.cron_month.command, locale );
+    let years = schedule.cron_year.as_ref().and_then( |year| date_phrase( CronPosition::Year, &year.command, locale ) );
+
+    locale.sentence( time, days, months, years )
+}
+
+/// The second, minute and hour fields, as clock times when they are fixed
+fn time_phrase( schedule: &CronSchedule, locale: &dyn CronLocale ) -> String {
+    let second = schedule.cron_second.as_ref().map( |second| &second.command );
+    let minute = &schedule.cron_minute.command;
+    let hour = &schedule.cron_hour.command;
+
+    if let Some( times ) = clock_times( second, minute, hour ) {
+        return locale.at_times( locale.list( times ) );
+    }
+
+    let fields = [ ( CronPosition::Second, second ), ( CronPosition::Minute, Some( minute ) ), ( CronPosition::Hour, Some( hour ) ) ];
+    let mut clauses: Vec<String> = vec![];
+    let mut previous_fixed = false;
+
+    for ( position, command ) in fields {
+        let Some( command ) = command else {
+            continue;
+        };
+
+        let phrase = match ( position, command ) {
+            ( _, CronCommand::Asterisk ) if previous_fixed => Some( locale.every( position ) ),
+            ( _, CronCommand::Asterisk ) if !clauses.is_empty() || position == CronPosition::Hour => None,
+            ( CronPosition::Hour, command ) => Some( hour_phrase( command, locale ) ),
+            ( position, command ) => Some( unit_phrase( position, command, locale ) )
+        };
+
+        // A fixed second or minute reads as part of the every minute or hour that follows it
+        match ( phrase, clauses.pop() ) {
+            ( Some( phrase ), Some( fixed ) ) if previous_fixed && matches!( command, CronCommand::Asterisk | CronCommand::Step(..) ) => {
+                clauses.push( locale.past( fixed, phrase ) );
+            },
+            ( phrase, last ) => clauses.extend( last.into_iter().chain( phrase ) )
+        }
+
+        previous_fixed = matches!( command, CronCommand::Number(_) );
+    }
+
+    locale.clauses( clauses )
+}
+
+/// Every combination of fixed hours and a fixed minute and second, `None` when any of them varies
+fn clock_times( second: Option<&CronCommand>, minute: &CronCommand, hour: &CronCommand ) -> Option<Vec<String>> {
+    let second = match second {
+        Some( CronCommand::Number( second ) ) => Some( *second ),
+        Some( _ ) => return None,
+        None => None
+    };
+
+    let CronCommand::Number( minute ) = minute else {
+        return None;
+    };
+
+    let hours = match hour {
+        CronCommand::Number( hour ) => vec![ *hour ],
+        CronCommand::List( items ) => items.iter().map( |item| match item {
+            CronCommand::Number( hour ) => Some( *hour ),
+            _ => None
+        }).collect::<Option<Vec<u32>>>()?,
+        _ => return None
+    };
+
+    // Quartz expressions always have seconds, on the minute they go without saying
+    Some( hours.into_iter().map( |hour| match second {
+        Some( second ) if second > 0 => format!( "{hour:02}:{minute:02}:{second:02}" ),
+        _ => format!( "{hour:02}:{minute:02}" )
+    }).collect() )
+}
+
+/// A second or minute field
+fn unit_phrase( position: CronPosition, command: &CronCommand, locale: &dyn CronLocale ) -> String {
+    match command {
+        CronCommand::Asterisk | CronCommand::NoSpecific => locale.every( position ),
+        CronCommand::Range( start, end ) => locale.within( position, locale.every( position ), *start, *end ),
+        CronCommand::Step( base, step ) => step_phrase( position, base, *step, locale ),
+        CronCommand::List(items) => locale.at( position, items_phrase( position, command, locale ), items.len() ),
+        command => locale.at( position, items_phrase( position, command, locale ), 1 )
+    }
+}
+
+fn hour_phrase( command: &CronCommand, locale: &dyn CronLocale ) -> String {
+    match command {
+        CronCommand::Number(n) => locale.hours( *n, *n ),
+        CronCommand::Range( start, end ) => locale.hours( *start, *end ),
+        CronCommand::Step( base, step ) => step_phrase( CronPosition::Hour, base, *step, locale ),
+        command => locale.during_hours( items_phrase( CronPosition::Hour, command, locale ) )
+    }
+}
+
+/// The day of month and day of week fields, joined by how the schedule matches them
+fn day_phrase( schedule: &CronSchedule, locale: &dyn CronLocale ) -> Option<String> {
+    let day_of_month = date_phrase( CronPosition::DayOfMonth, &schedule.cron_day_of_month.command, locale );
+    let day_of_week = date_phrase( CronPosition::DayOfWeek, &schedule.cron_day_of_week.command, locale );
+
+    match ( day_of_month, day_of_week ) {
+        ( Some( day_of_month ), Some( day_of_week ) ) if schedule.matches_either_day() => Some( locale.either( day_of_month, day_of_week ) ),
+        ( Some( day_of_month ), Some( day_of_week ) ) => Some( locale.both( day_of_month, day_of_week ) ),
+        ( day_of_month, day_of_week ) => day_of_month.or( day_of_week )
+    }
+}
+
+/// A day of month, day of week, month or year field, `None` when it does not restrict the schedule
+fn date_phrase( position: CronPosition, command: &CronCommand, locale: &dyn CronLocale ) -> Option<String> {
+    let items = match command {
+        CronCommand::Asterisk | CronCommand::NoSpecific => return None,
+        CronCommand::Range( 1, 5 ) if position == CronPosition::DayOfWeek => return Some( locale.monday_to_friday() ),
+        CronCommand::Step( base, step ) if position != CronPosition::DayOfWeek => return Some( step_phrase( position, base, *step, locale ) ),
+        CronCommand::LastDay(offset) if *offset > 0 => return Some( locale.days_before_last_day( locale.last_day( *offset ) ) ),
+        command => items_phrase( position, command, locale )
+    };
+
+    Some( match position {
+        CronPosition::DayOfMonth => locale.days_of_month( items ),
+        CronPosition::DayOfWeek => locale.weekdays( items ),
+        CronPosition::Month => locale.months( items ),
+        _ => locale.years( items )
+    })
+}
+
+fn step_phrase( position: CronPosition, base: &StepBase, step: u32, locale: &dyn CronLocale ) -> String {
+    let every = locale.every_n( position, step );
+
+    match base {
+        StepBase::Multiple | StepBase::Asterisk => every,
+        StepBase::Start(start) => locale.starting( position, every, *start ),
+        StepBase::Range( start, end ) => locale.within( position, every, *start, *end ),
+    }
+}
+
+/// The values, ranges and special days of a field as a list
+fn items_phrase( position: CronPosition, command: &CronCommand, locale: &dyn CronLocale ) -> String {
+    match command {
+        CronCommand::List(items) => locale.list( items.iter().map( |item| items_phrase( position, item, locale ) ).collect() ),
+        CronCommand::Asterisk | CronCommand::NoSpecific => locale.every( position ),
+        CronCommand::Number(n) => locale.value( position, *n ),
+        CronCommand::Range( start, end ) => locale.range( position, *start, *end ),
+        // Steps over a week are short enough to list
+        CronCommand::Step(..) if position == CronPosition::DayOfWeek => {
+            let days = ( 0..=6 ).filter( |day| command.is_valid( *day, 0, 6, &NaiveDateTime::MIN ) ).map( |day| locale.value( position, day ) ).collect();
+
+            locale.list( days )
+        },
+        CronCommand::Step( base, step ) => step_phrase( position, base, *step, locale ),
+        CronCommand::W(day) => locale.nearest_weekday( *day ),
+        CronCommand::LastDay(offset) => locale.last_day( *offset ),
+        CronCommand::LastWeekday => locale.last_weekday(),
+        CronCommand::Nth( day, n ) => locale.nth_weekday( *n, *day ),
+        CronCommand::L(day) => locale.last_of_month( *day ),
+    }
+}
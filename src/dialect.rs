@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+/// The flavour of cron syntax an expression is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CronDialect {
+    /// Vixie style, Sunday is 0 or 7 and `?` is not accepted
+    #[default]
+    Standard,
+    /// Quartz style, seconds are required, Sunday is 1 and one of the day fields must be `?`
+    Quartz
+}
+
+impl CronDialect {
+    /// What is added to a weekday's index to get its number in this dialect
+    pub fn weekday_offset( &self ) -> u32 {
+        match self {
+            CronDialect::Standard => 0,
+            CronDialect::Quartz => 1,
+        }
+    }
+}
+
+impl Display for CronDialect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            CronDialect::Standard => "Standard",
+            CronDialect::Quartz => "Quartz",
+        };
+
+        write!( f, "{display}" )
+    }
+}
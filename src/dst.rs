@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+/// What a schedule in local time does when daylight saving changes the clocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CronDstPolicy {
+    pub gap: CronGapPolicy,
+    pub overlap: CronOverlapPolicy
+}
+
+/// Wall times skipped when the clocks go forward, such as 02:30 on the spring change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CronGapPolicy {
+    /// The occurrence does not happen that day
+    #[default]
+    Skip,
+    /// The occurrence happens at the instant the clocks go forward
+    Shifted
+}
+
+/// Wall times repeated when the clocks go back, such as 01:30 on the autumn change in New York
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CronOverlapPolicy {
+    /// The occurrence happens the first time the wall clock shows it
+    #[default]
+    Once,
+    /// The occurrence happens both times the wall clock shows it
+    Twice
+}
+
+impl Display for CronGapPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            CronGapPolicy::Skip => "Skip",
+            CronGapPolicy::Shifted => "Shifted",
+        };
+
+        write!( f, "{display}" )
+    }
+}
+
+impl Display for CronOverlapPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            CronOverlapPolicy::Once => "Once",
+            CronOverlapPolicy::Twice => "Twice",
+        };
+
+        write!( f, "{display}" )
+    }
+}
@@ -1,69 +1,144 @@
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, ops::Range};
 
-#[derive(Debug)]
-pub struct CronNumberParseError {
-    details: String
-}
+use crate::position::CronPosition;
 
-impl CronNumberParseError {
-    pub fn new( position: &str, min: u32, max: u32 ) -> CronNumberParseError {
-        CronNumberParseError { details: format!("{} must be between {} and {} inclusive", position, min, max ) }
-    }
+/// Everything that can go wrong parsing a cron expression, spans are byte offsets into the expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CronError {
+    /// A number outside the values its field accepts
+    OutOfBounds { position: CronPosition, token: String, span: Range<usize>, min: u32, max: u32 },
+    /// A range whose start and end are the same
+    InvalidRange { position: CronPosition, token: String, span: Range<usize> },
+    /// Something that is not valid syntax, or not allowed in its field
+    InvalidArgument { position: CronPosition, token: String, span: Range<usize> },
+    /// A Quartz expression without `?` in exactly one of the day fields, the token is the day of week field
+    InvalidQuartzDays { day_of_month: String, token: String, span: Range<usize> },
+    /// An expression with too few or too many fields
+    InvalidFieldCount { token: String, span: Range<usize>, min: usize, max: usize, found: usize },
+    /// A valid expression that can never occur, such as `0 0 30 2 *`
+    Unsatisfiable { token: String, span: Range<usize> },
+    /// Words of an English phrase that are not part of the grammar, see `CronSchedule::from_english`
+    UnknownPhrase { token: String, span: Range<usize> },
+    /// Words of an English phrase that are understood but have no cron equivalent, such as "every 7 minutes"
+    UntranslatablePhrase { token: String, span: Range<usize> }
 }
-impl Display for CronNumberParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"{}", self.details )
+
+impl CronError {
+    /// The field the error is in, `None` when it is about the expression as a whole
+    pub fn position( &self ) -> Option<CronPosition> {
+        match self {
+            CronError::OutOfBounds { position, .. }
+            | CronError::InvalidRange { position, .. }
+            | CronError::InvalidArgument { position, .. } => Some( *position ),
+            CronError::InvalidQuartzDays { .. } => Some( CronPosition::DayOfWeek ),
+            CronError::InvalidFieldCount { .. }
+            | CronError::Unsatisfiable { .. }
+            | CronError::UnknownPhrase { .. }
+            | CronError::UntranslatablePhrase { .. } => None,
+        }
     }
-}
 
-impl Error for CronNumberParseError {
-    fn description(&self) -> &str {
-        &self.details
+    /// The text the error is about, as written in the expression
+    pub fn token( &self ) -> &str {
+        match self {
+            CronError::OutOfBounds { token, .. }
+            | CronError::InvalidRange { token, .. }
+            | CronError::InvalidArgument { token, .. }
+            | CronError::InvalidQuartzDays { token, .. }
+            | CronError::InvalidFieldCount { token, .. }
+            | CronError::Unsatisfiable { token, .. }
+            | CronError::UnknownPhrase { token, .. }
+            | CronError::UntranslatablePhrase { token, .. } => token,
+        }
     }
-}
 
-#[derive(Debug)]
-pub struct CronInvalidRange {
-    details: String
-}
-const DETAILS: &str = "Invalid range: the range min must be less than the range max";
+    /// Where the token is in the expression
+    pub fn span( &self ) -> Range<usize> {
+        match self {
+            CronError::OutOfBounds { span, .. }
+            | CronError::InvalidRange { span, .. }
+            | CronError::InvalidArgument { span, .. }
+            | CronError::InvalidQuartzDays { span, .. }
+            | CronError::InvalidFieldCount { span, .. }
+            | CronError::Unsatisfiable { span, .. }
+            | CronError::UnknownPhrase { span, .. }
+            | CronError::UntranslatablePhrase { span, .. } => span.clone(),
+        }
+    }
 
-impl CronInvalidRange {
-    pub fn new( position: &str ) -> CronNumberParseError {
-        CronNumberParseError { details: position.clone().into() }
+    /// The inclusive bounds the token should have been within
+    pub fn bounds( &self ) -> Option<( u32, u32 )> {
+        match self {
+            CronError::OutOfBounds { min, max, .. } => Some( ( *min, *max ) ),
+            _ => None
+        }
     }
 }
 
-impl Display for CronInvalidRange {
+impl Display for CronError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"{} {DETAILS}", self.details )
+        match self {
+            CronError::OutOfBounds { position, token, min, max, .. } => write!( f, "{position} value {token} must be between {min} and {max} inclusive" ),
+            CronError::InvalidRange { position, token, .. } => write!( f, "Invalid range {token} in {position}: the range start and end must differ" ),
+            CronError::InvalidArgument { position, token, .. } => write!( f, "The argument {token} is invalid in the {position} position" ),
+            CronError::InvalidQuartzDays { day_of_month, token, .. } => write!( f, "Quartz expressions need ? in exactly one of DayOfMonth and DayOfWeek, found {day_of_month} and {token}" ),
+            CronError::InvalidFieldCount { token, min, max, found, .. } => write!( f, "Invalid Cron string {token}: expected {min} to {max} fields, found {found}" ),
+            CronError::Unsatisfiable { token, .. } => write!( f, "The schedule {token} never occurs" ),
+            CronError::UnknownPhrase { token, .. } => write!( f, "Could not understand \"{token}\"" ),
+            CronError::UntranslatablePhrase { token, .. } => write!( f, "\"{token}\" has no cron equivalent" ),
+        }
     }
 }
 
-impl Error for CronInvalidRange {
-    fn description(&self) -> &str {
-        DETAILS
-    }
-}
+impl Error for CronError {}
 
+/// What is wrong with a single item of a field, before it is placed in the expression
 #[derive(Debug)]
-pub struct CronInvalidArgument {
-    details: String
+pub enum FieldError {
+    OutOfBounds { value: u32, min: u32, max: u32 },
+    InvalidRange,
+    InvalidArgument
 }
 
-impl CronInvalidArgument {
-    pub fn new( position: &str, arg: &str ) -> CronNumberParseError {
-        CronNumberParseError { details: format!("The argument {} is invalid in the {} position", arg, position ) }
-    }
-}
-impl Display for CronInvalidArgument {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"{}", self.details )
+impl FieldError {
+    /// Places the error in the expression, `item` is the text of the item and `offset` where it starts
+    pub fn at( self, position: CronPosition, item: &str, offset: usize ) -> CronError {
+        let whole = ( item.to_owned(), offset..offset + item.len() );
+
+        match self {
+            FieldError::OutOfBounds { value, min, max } => {
+                // Point at the number itself when it is written out, names are always in bounds
+                let ( token, span ) = find_number( item, value )
+                    .map( |range| ( item[ range.clone() ].to_owned(), offset + range.start..offset + range.end ) )
+                    .unwrap_or( whole );
+
+                CronError::OutOfBounds { position, token, span, min, max }
+            },
+            FieldError::InvalidRange => CronError::InvalidRange { position, token: whole.0, span: whole.1 },
+            FieldError::InvalidArgument => CronError::InvalidArgument { position, token: whole.0, span: whole.1 },
+        }
     }
 }
 
-impl Error for CronInvalidArgument {
-    fn description(&self) -> &str {
-        &self.details
+/// The byte range of the first run of digits in the item with the given value
+fn find_number( item: &str, value: u32 ) -> Option<Range<usize>> {
+    let bytes = item.as_bytes();
+    let mut start = 0;
+
+    while start < bytes.len() {
+        if !bytes[ start ].is_ascii_digit() {
+            start += 1;
+            continue;
+        }
+
+        let end = start + bytes[ start.. ].iter().take_while( |b| b.is_ascii_digit() ).count();
+
+        if item[ start..end ].parse::<u32>() == Ok( value ) {
+            return Some( start..end );
+        }
+
+        start = end;
     }
-}
\ No newline at end of file
+
+    None
+}
@@ -1,15 +1,58 @@
-
-use std::{error::Error};
-
-
-use chrono::{prelude::*};
+//! Parses cron expressions into a `CronSchedule` that checks dates and finds occurrences.
+//!
+//! The `cron!` macro, which checks an expression at compile time, is in the separate `croncron-macros`
+//! crate. A procedural macro crate cannot be re-exported by a crate it depends on, so add both:
+//!
+//! ```toml
+//! [dependencies]
+//! croncron = "0.1"
+//! croncron-macros = "0.1"
+//! ```
+//!
+//! ```ignore
+//! use croncron_macros::cron;
+//!
+//! let schedule = cron!( */15 9-17 * * MON-FRI );
+//! ```
+
+use std::{cell::Cell, collections::HashSet, fmt::Display, str::FromStr};
+
+
+use chrono::{prelude::*, LocalResult};
 
 mod errors;
 mod position;
 mod command;
+mod nickname;
+mod dialect;
+mod day_matching;
+mod dst;
+mod bitset;
+mod parser;
+mod describe;
+mod locale;
+mod natural;
+mod builder;
+mod occurrences;
+
+#[doc(hidden)]
+pub mod macro_support;
 
-use position::{*};
 use command::{*};
+use errors::FieldError;
+use bitset::CronBitset;
+use parser::parse_field;
+pub use errors::CronError;
+pub use builder::CronBuilder;
+pub use command::{DayOfWeek, Month};
+pub use position::CronPosition;
+pub use nickname::CronNickname;
+pub use dialect::CronDialect;
+pub use day_matching::CronDayMatching;
+pub use dst::{CronDstPolicy, CronGapPolicy, CronOverlapPolicy};
+pub use occurrences::{CronOccurrences, CronOccurrencesRev};
+pub use locale::{CronLocale, CronEnglish, CronGerman, CronFrench, CronJapanese};
+pub use chrono_tz::Tz;
 /*
 https://en.wikipedia.org/wiki/Cron
 # ┌───────────── minute (0 - 59)
@@ -24,6 +67,7 @@ https://en.wikipedia.org/wiki/Cron
 */
 
 #[cfg(test)]
+#[allow(clippy::match_like_matches_macro)]
 mod tests {
     use super::*;
 
@@ -72,7 +116,7 @@ mod tests {
         let c = CronSchedule::new( "15", "*", "*", "*", "*" ).unwrap();
 
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2020, 11, 28, 5, 15, 0).unwrap()
         );
     }
@@ -82,7 +126,7 @@ mod tests {
         let c = CronSchedule::new( "15", "*", "*", "*", "*" ).unwrap();
 
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 20, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 20, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2020, 11, 28, 6, 15, 0).unwrap()
         );
     }
@@ -91,7 +135,7 @@ mod tests {
     fn should_support_numbers_for_hour(){
         let c = CronSchedule::new( "*", "7", "*", "*", "*" ).unwrap();
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 20, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 20, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2020, 11, 28, 7, 0, 0).unwrap()
         );
     }
@@ -101,7 +145,7 @@ mod tests {
         let c = CronSchedule::new( "*", "*", "30", "*", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 1, 20, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 1, 20, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2020, 11, 30, 0, 0, 0).unwrap()
         );
     }
@@ -111,7 +155,7 @@ mod tests {
         let c = CronSchedule::new( "*", "*", "*", "1", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 12, 28, 1, 20, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 12, 28, 1, 20, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap()
         );
     }
@@ -121,7 +165,7 @@ mod tests {
         let c = CronSchedule::new( "*", "*", "*", "*", "3" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 20, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 20, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 30, 0, 0, 0).unwrap()
         );
     }
@@ -168,7 +212,7 @@ mod tests {
 
     #[test]
     fn should_error_on_incorrect_day_of_week_value(){
-        let did_error = match CronSchedule::new( "*", "*", "*", "*", "7" ) {
+        let did_error = match CronSchedule::new( "*", "*", "*", "*", "8" ) {
             Err(_e) => true,
             _ => false
         };
@@ -181,7 +225,7 @@ mod tests {
         let c = CronSchedule::new( "5-10", "*", "*", "*", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 5, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 5, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 28, 1, 6, 0).unwrap()
         );
     }
@@ -191,7 +235,7 @@ mod tests {
         let c = CronSchedule::new( "5-10", "*", "*", "*", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 1, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 1, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 28, 1, 5, 0).unwrap()
         );
     }
@@ -201,7 +245,7 @@ mod tests {
         let c = CronSchedule::new( "5-10", "*", "*", "*", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 11, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 11, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 28, 2, 5, 0).unwrap()
         );
     }
@@ -211,7 +255,7 @@ mod tests {
         let c = CronSchedule::new( "5-10", "*", "*", "*", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 6, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 6, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 28, 1, 7, 0).unwrap()
         );
     }
@@ -238,7 +282,7 @@ mod tests {
 
     #[test]
     fn should_fail_to_parse_range_invalid_range(){
-        let did_error = match CronSchedule::new( "48-32", "*", "*", "*", "7" ) {
+        let did_error = match CronSchedule::new( "32-32", "*", "*", "*", "7" ) {
             Err(_e) => true,
             _ => false
         };
@@ -275,17 +319,22 @@ mod tests {
         let c = CronSchedule::new( "*", "15-18", "*", "*", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 0, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 0, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 28, 15, 0, 0).unwrap()
         );
 
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 17, 0, 0).unwrap() ),
-            Utc.with_ymd_and_hms(2022, 11, 28, 18, 0, 0).unwrap()
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 17, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 28, 17, 1, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 18, 59, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 29, 15, 0, 0).unwrap()
         );
 
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 19, 0, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 19, 0, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2022, 11, 29, 15, 0, 0).unwrap()
         );
     }
@@ -295,213 +344,1862 @@ mod tests {
         let c = CronSchedule::new( "*", "*", "*", "2-4", "*" ).unwrap();
         
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 0, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 0, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap()
         );
 
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 3, 2, 17, 0, 0).unwrap() ),
-            Utc.with_ymd_and_hms(2022, 4, 1, 0, 0, 0).unwrap()
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 3, 2, 17, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 3, 2, 17, 1, 0).unwrap()
         );
 
         assert_eq!(
-            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 4, 4, 19, 0, 0).unwrap() ),
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 4, 30, 23, 59, 0).unwrap() ).unwrap(),
             Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap()
         );
     }
 
     #[test]
-    fn should_only_parse_w_in_day_of_month(){
-        // let did_error = match CronSchedule::new( "5W", "*", "*", "*", "*" ) {
-        //     Err(_e) => true,
-        //     _ => false
-        // };
-        
-        // assert!( did_error );
+    fn should_match_list_values(){
+        matches_schedule!( "0,15,30,45", "*", "*", "*", "*", "2022-12-19 11:30:00Z");
+        matches_schedule!( "*", "*", "*", "*", "Monday,Wednesday,Friday", "2022-12-21 11:36:00Z");
+        doesnt_match_schedule!( "0,15,30,45", "*", "*", "*", "*", "2022-12-19 11:36:00Z");
+    }
 
-        // let did_error = match CronSchedule::new( "*", "5W", "*", "*", "*" ) {
-        //     Err(_e) => true,
-        //     _ => false
-        // };
-        
-        // assert!( did_error );
+    #[test]
+    fn should_increment_minute_to_next_list_value(){
+        let c = CronSchedule::new( "0,15,30,45", "*", "*", "*", "*" ).unwrap();
 
-        // let did_error = match CronSchedule::new( "*", "*", "*", "5W", "*" ) {
-        //     Err(_e) => true,
-        //     _ => false
-        // };
-        
-        // assert!( did_error );
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 20, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 28, 1, 30, 0).unwrap()
+        );
 
-        // let did_error = match CronSchedule::new( "*", "*", "*", "*", "5W" ) {
-        //     Err(_e) => true,
-        //     _ => false
-        // };
-        
-        // assert!( did_error );
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 50, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 28, 2, 0, 0).unwrap()
+        );
+    }
 
-        let did_error = match CronSchedule::new( "*", "*", "5W", "*", "*" ) {
-            Err(_e) => true,
-            _ => false
-        };
-        
-        assert!( !did_error );
+    #[test]
+    fn should_fail_to_parse_list_with_invalid_element(){
+        assert!( CronSchedule::new( "0,15,60", "*", "*", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "1-3,20-25", "*", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "*", "1,5W" ).is_err() );
     }
-}
 
+    #[test]
+    fn from_str_should_round_trip_list(){
+        let c = CronSchedule::from_str( "0,15,30,45 * * * *" ).unwrap();
 
+        assert_eq!( c.to_string(), "0,15,30,45 * * * *" );
+    }
 
-macro_rules! validate_number {
-    ($position:path where $n:ident between $min:literal and $max:literal) => {
-        {
-            #[allow(unused_comparisons)]
-            if $n > $max || $n < $min {
-                return Err( Box::new( errors::CronNumberParseError::new( &$position.to_string(), $min, $max ) ) );
-            }
-        }
-    };
-}
+    #[test]
+    fn should_match_step_values(){
+        matches_schedule!( "*/15", "*", "*", "*", "*", "2022-12-19 11:45:00Z");
+        matches_schedule!( "10-50/5", "*", "*", "*", "*", "2022-12-19 11:35:00Z");
+        matches_schedule!( "5/15", "*", "*", "*", "*", "2022-12-19 11:50:00Z");
+        matches_schedule!( "*", "*", "*/10", "*", "*", "2022-12-21 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*/3", "*", "2022-10-19 11:36:00Z");
+        doesnt_match_schedule!( "10-50/5", "*", "*", "*", "*", "2022-12-19 11:55:00Z");
+        doesnt_match_schedule!( "5/15", "*", "*", "*", "*", "2022-12-19 11:45:00Z");
+        doesnt_match_schedule!( "*", "*", "*/10", "*", "*", "2022-12-20 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*/3", "*", "2022-12-19 11:36:00Z");
+    }
 
-macro_rules! validate_range {
-    ($position:path where $a:ident to $b:ident between $min:literal and $max:literal) => {
-        {
-            if $b > $max {
-                return Err( Box::new( errors::CronNumberParseError::new( &$position.to_string(), $min, $max ) ) );
-            }
-            else if $a > $max {
-                return Err( Box::new( errors::CronNumberParseError::new( &$position.to_string(), $min, $max ) ) );
-            }
-            else if $a >= $b {
-                return Err( Box::new( errors::CronInvalidRange::new( &$position.to_string() ) ) );
-            }
-        }
-    };
-}
+    #[test]
+    fn should_increment_to_next_step_value(){
+        let c = CronSchedule::new( "10-50/5", "*", "*", "*", "*" ).unwrap();
 
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 12, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 28, 1, 15, 0).unwrap()
+        );
 
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 50, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 28, 2, 10, 0).unwrap()
+        );
 
-#[derive(Debug)]
-struct CronArg{
-    position: CronPosition,
-    command: CronCommand
-}
+        let c = CronSchedule::new( "*", "*", "*", "*/3", "*" ).unwrap();
 
-impl CronArg {
-    fn parse( position: CronPosition, command_string: &str ) -> Result<CronArg, Box<dyn Error>>  {
-        let command = CronCommand::from_str(command_string )?;
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 2, 10, 1, 12, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 4, 1, 0, 0, 0).unwrap()
+        );
+    }
 
-        match command {
-            CronCommand::Number(n) => {
-                match position {
-                    CronPosition::Minute => validate_number!( CronPosition::Minute where n between 0 and 59 ),
-                    CronPosition::Hour => validate_number!( CronPosition::Hour where n between 0 and 23 ),
-                    CronPosition::DayOfMonth => validate_number!( CronPosition::DayOfMonth where n between 1 and 31 ),
-                    CronPosition::Month => validate_number!( CronPosition::Month where n between 1 and 12 ),
-                    CronPosition::DayOfWeek => validate_number!( CronPosition::DayOfWeek where n between 0 and 6 ),
-                }
-            },
-            CronCommand::Range(min,max) => {
-                match position {
-                    CronPosition::Minute => validate_range!( CronPosition::Minute where min to max between 0 and 59 ),
-                    CronPosition::Hour => validate_range!( CronPosition::Hour where min to max between 0 and 23 ),
-                    CronPosition::DayOfMonth => validate_range!( CronPosition::DayOfMonth where min to max between 1 and 31 ),
-                    CronPosition::Month => validate_range!( CronPosition::Month where min to max between 1 and 12 ),
-                    CronPosition::DayOfWeek => validate_range!( CronPosition::DayOfWeek where min to max between 0 and 6 ),
-                }
-            },
-            CronCommand::W(n) => {
-                match position {
-                    CronPosition::DayOfMonth => {},
-                    _ => {
-                        return Err( Box::new( errors::CronInvalidArgument::new( &position.to_string(), &command.to_string() ) ) );
-                    },
-                }
-            }
-            _ => {}
+    #[test]
+    fn should_fail_to_parse_invalid_step(){
+        assert!( CronSchedule::new( "*/0", "*", "*", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*/24", "*", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "0/5", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "10-13/2", "*" ).is_err() );
+        assert!( CronSchedule::new( "50-60/5", "*", "*", "*", "*" ).is_err() );
+    }
+
+    #[test]
+    fn from_str_should_round_trip_step(){
+        let c = CronSchedule::from_str( "*/15 5/2 1-15/3 /2 *" ).unwrap();
+
+        assert_eq!( c.to_string(), "*/15 5/2 1-15/3 /2 *" );
+    }
+
+    #[test]
+    fn to_string_should_parse_back_to_the_same_schedule(){
+        let expressions = [
+            "0 9-17 * * 1-5", "5-10,20 22-2 L-3,15W * 5L", "0 0 LW 1-6 1#2", "30 0 0 1 JAN-MAR MON-FRI 2030-2035",
+            "@daily", "@reboot", "0 9 * * 7", "*/15 5/2 1-15/3 /2 *"
+        ];
+
+        for expression in expressions {
+            let c = CronSchedule::from_str( expression ).unwrap();
+            let parsed = CronSchedule::from_str( &c.to_string() ).unwrap();
+
+            assert_eq!( parsed.to_string(), c.to_string(), "{expression}" );
         }
 
-        Ok( CronArg{ position, command } )
+        assert_eq!( CronSchedule::from_str( "30 0 0 1 JAN-MAR MON-FRI 2030-2035" ).unwrap().to_string(), "30 0 0 1 1-3 1-5 2030-2035" );
+
+        let c = CronSchedule::parse_with_dialect( "0 15 10 ? JAN-MAR 2-6", CronDialect::Quartz ).unwrap();
+
+        assert_eq!( CronSchedule::parse_with_dialect( &c.to_string(), CronDialect::Quartz ).unwrap().to_string(), "0 15 10 ? 1-3 2-6" );
     }
 
-    fn update_date( &self, date: &DateTime<Utc> ) -> DateTime<Utc> {
-        let current_value = self.position.get_value_from_date( date );
-        let next_value = self.command.get_next_value(current_value, self.position.get_min(), self.position.get_max() );
-        
-        // self.0.update_date(date, next_value)
-        match self.command {
-            CronCommand::Asterisk => date.clone(),
-            _ => {
-                self.position.update_date(date, next_value)
-            }
+    #[test]
+    fn should_normalize_spellings_of_the_same_schedule(){
+        let normalize = |expression: &str| CronSchedule::from_str( expression ).unwrap().normalize().to_string();
+
+        assert_eq!( normalize( "0-59 */1 1-31 JAN-DEC SUN-SAT" ), "* * * * *" );
+        assert_eq!( normalize( "30,0,15,45,0 9,10,11,12,13 * * 1,2,3,4,5" ), "*/15 9-13 * * 1-5" );
+        assert_eq!( normalize( "0 12 * * fri,MON,7" ), "0 12 * * 0,1,5" );
+        assert_eq!( normalize( "5/10 0 * * *" ), "5/10 0 * * *" );
+        assert_eq!( normalize( "0,5,10 0 * * *" ), "0,5,10 0 * * *" );
+        assert_eq!( normalize( "0 0 0 L,15,LW,1 * * *" ), "0 0 1,15,L,LW * *" );
+        assert_eq!( normalize( "@daily" ), normalize( "0 0 * * *" ) );
+        assert_eq!( normalize( "@reboot" ), "@reboot" );
+        assert_eq!( normalize( "0 30 6 * * * 1970-2099" ), "30 6 * * *" );
+        assert_eq!( normalize( "15 30 6 * * * 2030" ), "15 30 6 * * * 2030" );
+    }
+
+    #[test]
+    fn should_normalize_day_fields_without_changing_how_they_combine(){
+        let normalize = |expression: &str| CronSchedule::from_str( expression ).unwrap().normalize().to_string();
+
+        // Either day matches, and every day of the month is one of them
+        assert_eq!( normalize( "0 0 1-31 * MON" ), "0 0 * * *" );
+        assert_eq!( normalize( "0 0 1,3,5,7,9,11,13,15,17,19,21,23,25,27,29,31 * MON" ), "0 0 1/2 * 1" );
+        assert_eq!( normalize( "0 0 */2 * MON" ), "0 0 */2 * 1" );
+        assert_eq!( normalize( "0 0 1-31 * 1-5" ), "0 0 * * *" );
+
+        let c = CronSchedule::from_str( "0 0 1-31 * MON" ).unwrap().with_day_matching( CronDayMatching::Both );
+
+        assert_eq!( c.normalize().to_string(), "0 0 * * 1" );
+
+        for expression in [ "0 0 1,3,5,7,9,11,13,15,17,19,21,23,25,27,29,31 * MON", "0 0 */2 * MON", "0 0 1-31 * MON" ] {
+            let c = CronSchedule::from_str( expression ).unwrap();
+            let normalized = c.normalize();
+            let start = Utc.with_ymd_and_hms( 2023, 1, 1, 0, 0, 0 ).unwrap();
+
+            assert!( c.occurrences( start ).zip( normalized.occurrences( start ) ).take( 200 ).all( |( a, b )| a == b ), "{expression}" );
         }
     }
 
-    pub fn check_date( &self, date: &DateTime<Utc> ) -> bool {
-        let current_value = self.position.get_value_from_date( date );
-        self.command.is_valid(current_value, date)
+    #[test]
+    fn should_match_names_in_any_case(){
+        matches_schedule!( "*", "*", "*", "*", "MON", "2022-12-19 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "mon-fri", "2022-12-23 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "JAN,jul", "*", "2022-07-19 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "Feb-April", "*", "2022-03-19 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "Tue,THURSDAY", "2022-12-22 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*", "MON-FRI", "2022-12-24 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "JAN,JUL", "*", "2022-12-19 11:36:00Z");
     }
-}
 
-#[derive(Debug)]
-pub struct CronSchedule {
-    cron_minute: CronArg,
-    cron_hour: CronArg,
-    cron_day_of_month: CronArg,
-    cron_month: CronArg,
-    cron_day_of_week: CronArg
-}
+    #[test]
+    fn should_fail_to_parse_names_in_wrong_position(){
+        assert!( CronSchedule::new( "*", "*", "*", "MON", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "*", "JAN" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "*", "Funday" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "MON", "*", "*" ).is_err() );
+    }
 
+    #[test]
+    fn from_str_should_accept_seconds_and_year(){
+        let c = CronSchedule::from_str( "30 15 * * * *" ).unwrap();
+        assert_eq!( c.to_string(), "30 15 * * * *" );
 
-impl CronSchedule {
+        let c = CronSchedule::from_str( "0 0 12 * * * 2030" ).unwrap();
+        assert_eq!( c.to_string(), "0 0 12 * * * 2030" );
 
-    pub fn new( minute: &str, hour: &str, day_of_month: &str, month: &str, day_of_week: &str ) -> Result<CronSchedule, Box<dyn Error>> {
-        Ok(CronSchedule {
-            cron_minute: CronArg::parse( CronPosition::Minute, minute )?,
-            cron_hour: CronArg::parse( CronPosition::Hour, hour )?,
-            cron_day_of_month: CronArg::parse( CronPosition::DayOfMonth, day_of_month )?,
-            cron_month: CronArg::parse( CronPosition::Month, month )?,
-            cron_day_of_week: CronArg::parse( CronPosition::DayOfWeek, day_of_week )?,
-        })
+        assert!( CronSchedule::from_str( "* * * *" ).is_err() );
+        assert!( CronSchedule::from_str( "* * * * * * * *" ).is_err() );
+        assert!( CronSchedule::from_str( "60 * * * * *" ).is_err() );
+        assert!( CronSchedule::from_str( "* * * * * * 1969" ).is_err() );
     }
 
-    pub fn check_date( &self, date: &DateTime<Utc> ) -> bool {
-        self.cron_day_of_week.check_date( date )
-        && self.cron_month.check_date( &date )
-        && self.cron_day_of_month.check_date( &date )
-        && self.cron_hour.check_date( &date )
-        && self.cron_minute.check_date( &date )
+    #[test]
+    fn should_match_seconds_and_year(){
+        let c = CronSchedule::from_str( "*/15 36 11 * * *" ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 11, 36, 45).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 11, 36, 10).unwrap() ) );
+
+        let c = CronSchedule::from_str( "0 * * * * * 2022-2023" ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2023, 12, 19, 11, 36, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2024, 12, 19, 11, 36, 0).unwrap() ) );
+    }
+
+    #[test]
+    fn should_return_next_occurrence_to_the_second(){
+        let c = CronSchedule::from_str( "30 15 * * * *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 45).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2020, 11, 28, 5, 15, 30).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "*/10 * * * * *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 3).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 10).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 55).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2020, 11, 28, 5, 1, 0).unwrap()
+        );
     }
 
-    pub fn to_string( &self ) -> String {
-        format!(
-            "{} {} {} {} {}",
-            self.cron_minute.command.to_string(),
-            self.cron_hour.command.to_string(),
-            self.cron_day_of_month.command.to_string(),
-            self.cron_month.command.to_string(),
-            self.cron_day_of_week.command.to_string(),
-        )
+    #[test]
+    fn should_jump_to_year(){
+        let c = CronSchedule::from_str( "* * * * * * 2030" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 0, 45).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap()
+        );
     }
 
-    pub fn from_str( cron_string: &str ) -> Result<CronSchedule, String> {
-        let parts: Vec<&str> = cron_string.split( ' ' ).collect();
+    #[test]
+    fn from_str_should_parse_nicknames(){
+        let c = CronSchedule::from_str( "@daily" ).unwrap();
 
-        if parts.len() != 5 {
-            return Err( format!( "Invalid Cron string {}", cron_string ) );
-        }
+        assert_eq!( c.to_string(), "@daily" );
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 0, 0, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 0, 1, 0).unwrap() ) );
 
-        match CronSchedule::new( parts[ 0 ], parts[ 1 ], parts[ 2 ], parts[ 3 ], parts[ 4 ] ) {
-            Ok( c ) => Ok( c ),
-            _ => Err( format!( "Invalid Cron string {}", cron_string ) )
-        }
+        let c = CronSchedule::from_str( "@yearly" ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap() ) );
+
+        let c = CronSchedule::from_str( "@weekly" ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 18, 0, 0, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 0, 0, 0).unwrap() ) );
+
+        assert!( CronSchedule::from_str( "@fortnightly" ).is_err() );
+    }
+
+    #[test]
+    fn should_return_next_hourly_occurrence(){
+        let c = CronSchedule::from_str( "@hourly" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 20, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2020, 11, 28, 6, 0, 0).unwrap()
+        );
     }
 
-    pub fn get_next_occurrence( &self, start: DateTime<Utc> ) -> DateTime<Utc> {
-        let mut date = self.cron_day_of_week.update_date( &start );
-        date = self.cron_month.update_date( &date );
-        date = self.cron_day_of_month.update_date( &date );
-        date = self.cron_hour.update_date( &date );
-        self.cron_minute.update_date( &date )
+    #[test]
+    fn reboot_should_never_occur(){
+        let c = CronSchedule::from_str( "@reboot" ).unwrap();
+
+        assert!( c.is_reboot() );
+        assert_eq!( c.to_string(), "@reboot" );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 0, 0, 0).unwrap() ) );
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2020, 11, 28, 5, 20, 0).unwrap() ),
+            None
+        );
+        assert!( !CronSchedule::from_str( "@daily" ).unwrap().is_reboot() );
     }
-}
 
+    #[test]
+    fn should_match_nth_weekday(){
+        matches_schedule!( "*", "*", "*", "*", "2#2", "2022-12-13 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "TUE#2", "2022-12-13 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*", "2#2", "2022-12-20 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*", "2#2", "2022-12-14 11:36:00Z");
+    }
+
+    #[test]
+    fn should_return_next_nth_weekday(){
+        let c = CronSchedule::new( "30", "9", "*", "*", "2#2" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 1, 20, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 13, 9, 30, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "*", "*", "MON#5" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 1, 1, 20, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 30, 9, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_nth_weekday(){
+        assert!( CronSchedule::new( "*", "*", "*", "*", "2#0" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "*", "2#6" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "*", "8#2" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "2#2", "*", "*" ).is_err() );
+    }
+
+    #[test]
+    fn should_match_l_family(){
+        matches_schedule!( "*", "*", "L", "*", "*", "2024-02-29 11:36:00Z");
+        matches_schedule!( "*", "*", "L", "*", "*", "2023-02-28 11:36:00Z");
+        matches_schedule!( "*", "*", "L-3", "*", "*", "2023-04-27 11:36:00Z");
+        matches_schedule!( "*", "*", "LW", "*", "*", "2023-04-28 11:36:00Z");
+        matches_schedule!( "*", "*", "15W", "*", "*", "2023-04-14 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "5L", "2022-12-30 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "L", "*", "*", "2024-02-28 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "LW", "*", "*", "2023-04-30 11:36:00Z");
+    }
+
+    #[test]
+    fn should_return_next_l_family_occurrence(){
+        let c = CronSchedule::new( "30", "9", "L", "*", "*" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2024, 2, 10, 1, 20, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 29, 9, 30, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "LW", "*", "*" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 4, 28, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 5, 31, 9, 30, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "31W", "*", "*" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 11, 2, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 12, 29, 9, 30, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "*", "*", "5L" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 1, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 30, 9, 30, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "1,L", "*", "*" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 4, 10, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 4, 30, 9, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_only_parse_l_family_in_its_position(){
+        assert!( CronSchedule::new( "*", "*", "*", "*", "L" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "*", "*", "LW" ).is_err() );
+        assert!( CronSchedule::new( "*", "L-3", "*", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "5L", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "L-31", "*", "*" ).is_err() );
+        assert!( CronSchedule::new( "*", "*", "32W", "*", "*" ).is_err() );
+    }
+
+    #[test]
+    fn should_treat_seven_as_sunday(){
+        matches_schedule!( "*", "*", "*", "*", "7", "2022-12-18 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "5-7", "2022-12-18 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "1-7", "2022-12-18 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "0-7", "2022-12-19 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*", "7", "2022-12-19 11:36:00Z");
+
+        matches_schedule!( "*", "*", "*", "*", "7/2", "2022-12-18 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*", "7/2", "2022-12-20 11:36:00Z");
+
+        let c = CronSchedule::from_str( "0 0 * * 7/2" ).unwrap();
+
+        assert_eq!( c.to_string(), "0 0 * * 0" );
+        assert_eq!( c.normalize().to_string(), "0 0 * * 0" );
+    }
+
+    #[test]
+    fn should_match_wrap_around_ranges(){
+        matches_schedule!( "*", "22-2", "*", "*", "*", "2022-12-19 23:36:00Z");
+        matches_schedule!( "*", "22-2", "*", "*", "*", "2022-12-19 01:36:00Z");
+        matches_schedule!( "*", "*", "*", "*", "FRI-MON", "2022-12-18 11:36:00Z");
+        matches_schedule!( "*", "*", "*", "NOV-FEB", "*", "2022-01-18 11:36:00Z");
+        doesnt_match_schedule!( "*", "22-2", "*", "*", "*", "2022-12-19 11:36:00Z");
+        doesnt_match_schedule!( "*", "*", "*", "*", "FRI-MON", "2022-12-20 11:36:00Z");
+    }
+
+    #[test]
+    fn should_increment_wrap_around_range(){
+        let c = CronSchedule::new( "*", "22-2", "*", "*", "*" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 10, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 28, 22, 0, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 11, 28, 23, 59, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 11, 29, 0, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "*", "*", "FRI-MON" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 17, 10, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 18, 9, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_quartz_expressions(){
+        let c = CronSchedule::parse_with_dialect( "0 30 9 ? * 2-6", CronDialect::Quartz ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 9, 30, 0).unwrap() ) );
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 23, 9, 30, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 24, 9, 30, 0).unwrap() ) );
+
+        let c = CronSchedule::parse_with_dialect( "0 0 12 ? * 1", CronDialect::Quartz ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 18, 12, 0, 0).unwrap() ) );
+
+        let c = CronSchedule::parse_with_dialect( "0 0 12 ? * MON#2 2023", CronDialect::Quartz ).unwrap();
+
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2023, 1, 9, 12, 0, 0).unwrap() ) );
+    }
+
+    #[test]
+    fn should_enforce_quartz_day_rule(){
+        let error = CronSchedule::parse_with_dialect( "0 0 12 * * 1", CronDialect::Quartz ).unwrap_err();
+        assert!( error.to_string().contains( "exactly one of DayOfMonth and DayOfWeek" ) );
+        assert_eq!( error.span(), 11..12 );
+
+        assert!( CronSchedule::parse_with_dialect( "0 0 12 ? * ?", CronDialect::Quartz ).is_err() );
+        assert!( CronSchedule::parse_with_dialect( "0 12 ? * 1", CronDialect::Quartz ).is_err() );
+        assert!( CronSchedule::parse_with_dialect( "0 0 12 ? * 0", CronDialect::Quartz ).is_err() );
+        assert!( CronSchedule::parse_with_dialect( "0 0 12 ? * 8", CronDialect::Quartz ).is_err() );
+        assert!( CronSchedule::parse_with_dialect( "0 ? 12 1 * ?", CronDialect::Quartz ).is_err() );
+        assert!( CronSchedule::from_str( "0 12 ? * 1" ).is_err() );
+    }
+
+    #[test]
+    fn should_print_quartz_form(){
+        let c = CronSchedule::parse_with_dialect( "0 15 10 ? * 6L", CronDialect::Quartz ).unwrap();
+
+        assert_eq!( c.to_string(), "0 15 10 ? * 6L" );
+        assert_eq!( c.to_dialect_string( &CronDialect::Standard ).unwrap(), "0 15 10 * * 5L" );
+
+        let c = CronSchedule::from_str( "15 10 * * 1" ).unwrap();
+
+        assert_eq!( c.to_dialect_string( &CronDialect::Quartz ).unwrap(), "0 15 10 ? * 2" );
+
+        let c = CronSchedule::from_str( "15 10 1 * *" ).unwrap();
+
+        assert_eq!( c.to_dialect_string( &CronDialect::Quartz ).unwrap(), "0 15 10 1 * ?" );
+    }
+
+    #[test]
+    fn should_have_no_quartz_form_when_both_day_fields_are_restricted(){
+        let c = CronSchedule::from_str( "0 0 1 * 1" ).unwrap();
+
+        assert_eq!( c.to_dialect_string( &CronDialect::Quartz ), None );
+        assert_eq!( c.to_dialect_string( &CronDialect::Standard ).unwrap(), "0 0 1 * 1" );
+        assert_eq!( c.to_string(), "0 0 1 * 1" );
+    }
+
+    #[test]
+    fn should_match_either_day_field_when_both_restricted(){
+        matches_schedule!( "0", "0", "1,15", "*", "MON", "2022-12-15 00:00:00Z");
+        matches_schedule!( "0", "0", "1,15", "*", "MON", "2022-12-19 00:00:00Z");
+        doesnt_match_schedule!( "0", "0", "1,15", "*", "MON", "2022-12-20 00:00:00Z");
+        matches_schedule!( "0", "0", "*/2", "*", "MON", "2022-12-19 00:00:00Z");
+        doesnt_match_schedule!( "0", "0", "*/2", "*", "MON", "2022-12-26 00:00:00Z");
+        doesnt_match_schedule!( "0", "0", "1", "*", "*", "2022-12-19 00:00:00Z");
+    }
+
+    #[test]
+    fn should_match_both_day_fields_when_chosen(){
+        let c = CronSchedule::new( "0", "0", "1,15", "*", "MON" ).unwrap().with_day_matching( CronDayMatching::Both );
+
+        assert_eq!( c.day_matching(), CronDayMatching::Both );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 15, 0, 0, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 0, 0, 0).unwrap() ) );
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap() ) );
+    }
+
+    #[test]
+    fn should_return_next_occurrence_of_either_day_field(){
+        let c = CronSchedule::new( "30", "9", "15", "*", "MON" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 1, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 5, 9, 30, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 13, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 15, 9, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_match_in_schedule_time_zone(){
+        let c = CronSchedule::from_str( "0 9 * * MON-FRI" ).unwrap().with_time_zone( Tz::Europe__Berlin );
+
+        assert_eq!( c.time_zone(), Tz::Europe__Berlin );
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 8, 0, 0).unwrap() ) );
+        assert!( !c.check_date( &Utc.with_ymd_and_hms(2022, 12, 19, 9, 0, 0).unwrap() ) );
+        assert!( c.check_date( &Utc.with_ymd_and_hms(2023, 6, 19, 7, 0, 0).unwrap() ) );
+        assert!( c.check_date( &Tz::Asia__Tokyo.with_ymd_and_hms(2023, 6, 19, 16, 0, 0).unwrap() ) );
+    }
+
+    #[test]
+    fn should_return_next_occurrence_in_start_time_zone(){
+        let c = CronSchedule::from_str( "30 9 * * *" ).unwrap().with_time_zone( Tz::America__New_York );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 19, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 19, 14, 30, 0).unwrap()
+        );
+
+        let offset = FixedOffset::east_opt( 9 * 3600 ).unwrap();
+        let next = c.get_next_occurrence( offset.with_ymd_and_hms(2022, 12, 20, 0, 0, 0).unwrap() ).unwrap();
+
+        assert_eq!( next, offset.with_ymd_and_hms(2022, 12, 20, 23, 30, 0).unwrap() );
+        assert_eq!( next.offset(), &offset );
+    }
+
+    #[test]
+    fn should_skip_wall_times_that_do_not_exist(){
+        let c = CronSchedule::from_str( "30 2 * * *" ).unwrap().with_time_zone( Tz::Europe__Berlin );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 3, 25, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 27, 0, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_run_skipped_wall_time_when_clocks_go_forward(){
+        let shifted = CronDstPolicy { gap: CronGapPolicy::Shifted, ..CronDstPolicy::default() };
+
+        let c = CronSchedule::from_str( "30 2 * * *" ).unwrap().with_time_zone( Tz::Europe__Berlin ).with_dst_policy( shifted );
+
+        assert_eq!( c.dst_policy(), shifted );
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 3, 25, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 26, 1, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "30 2 * * *" ).unwrap().with_time_zone( Tz::America__New_York ).with_dst_policy( shifted );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 3, 11, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 12, 7, 0, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 3, 12, 7, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 13, 6, 30, 0).unwrap()
+        );
+
+        // Lord Howe Island only moves its clocks by half an hour
+        let c = CronSchedule::from_str( "15 2 * * *" ).unwrap().with_time_zone( Tz::Australia__Lord_Howe ).with_dst_policy( shifted );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 9, 30, 14, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 9, 30, 15, 30, 0).unwrap()
+        );
+
+        let c = c.with_dst_policy( CronDstPolicy::default() );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 9, 30, 14, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 10, 1, 15, 15, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_run_repeated_wall_time_once_when_clocks_go_back(){
+        let c = CronSchedule::from_str( "30 1 * * *" ).unwrap().with_time_zone( Tz::America__New_York );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 4, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 5, 5, 30, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 5, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 6, 6, 30, 0).unwrap()
+        );
+
+        // Starting in the second pass, the wall time has already happened once
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 6, 10, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 6, 6, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_run_repeated_wall_time_twice_when_chosen(){
+        let twice = CronDstPolicy { overlap: CronOverlapPolicy::Twice, ..CronDstPolicy::default() };
+
+        let c = CronSchedule::from_str( "30 1 * * *" ).unwrap().with_time_zone( Tz::America__New_York ).with_dst_policy( twice );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 5, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 5, 6, 30, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 6, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 6, 6, 30, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "30 2 * * *" ).unwrap().with_time_zone( Tz::Australia__Sydney ).with_dst_policy( twice );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 4, 1, 14, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 4, 1, 15, 30, 0).unwrap()
+        );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 4, 1, 15, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 4, 1, 16, 30, 0).unwrap()
+        );
+
+        // An hourly job in the first pass still runs at the start of the second pass
+        let c = CronSchedule::from_str( "0 * * * *" ).unwrap().with_time_zone( Tz::Europe__Berlin ).with_dst_policy( twice );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 10, 29, 0, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 10, 29, 1, 0, 0).unwrap()
+        );
+
+        let c = c.with_dst_policy( CronDstPolicy::default() );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 10, 29, 0, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 10, 29, 2, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_carry_past_end_of_day_month_and_year(){
+        let c = CronSchedule::from_str( "15 * * * *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 31, 23, 40, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 15, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "0 9 * * *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 4, 30, 23, 30, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 5, 1, 9, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "30 * * * * *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 31, 23, 59, 45).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 30).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_recheck_fields_after_carry(){
+        let c = CronSchedule::from_str( "0 0 31 * *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "0 0 29 2 *" ).unwrap();
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::new( "30", "9", "15", "*", "MON" ).unwrap().with_day_matching( CronDayMatching::Both );
+
+        assert_eq!(
+            c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 12, 1, 12, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 5, 15, 9, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn next_occurrence_should_be_the_first_matching_minute(){
+        let expressions = [ "*/7 */5 * * *", "0 0 1,15 * MON", "30 22-2 * * FRI-MON", "0 12 L * *", "0 9 * * 2#2", "45 23 LW * *" ];
+        let mut seed: i64 = 7;
+
+        for expression in expressions {
+            let c = CronSchedule::from_str( expression ).unwrap();
+
+            for _ in 0..10 {
+                seed = ( seed * 1103515245 + 12345 ) % 2147483648;
+
+                let start = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::minutes( seed % ( 366 * 24 * 60 ) );
+                let next = c.get_next_occurrence( start ).unwrap();
+
+                assert!( c.check_date( &next ), "{expression} from {start} gave {next}" );
+
+                let mut minute = start.with_second( 0 ).unwrap() + chrono::Duration::minutes( 1 );
+
+                while minute < next {
+                    assert!( !c.check_date( &minute ), "{expression} from {start} skipped {minute}" );
+                    minute += chrono::Duration::minutes( 1 );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_reject_schedules_that_never_occur(){
+        let error = CronSchedule::from_str( "0 0 30 2 *" ).unwrap_err();
+
+        assert!( matches!( error, CronError::Unsatisfiable { .. } ) );
+        assert_eq!( error.span(), 0..10 );
+        assert!( CronSchedule::from_str( "0 0 31 APR,JUN *" ).is_err() );
+        assert!( CronSchedule::from_str( "0 0 0 29 2 * 2023" ).is_err() );
+
+        assert!( CronSchedule::from_str( "0 0 29 2 *" ).is_ok() );
+        assert!( CronSchedule::from_str( "0 0 * 2 MON#5" ).is_ok() );
+        assert!( CronSchedule::from_str( "0 0 30 2 MON" ).is_ok() );
+    }
+
+    #[test]
+    fn should_return_none_when_schedule_never_occurs(){
+        let c = CronSchedule::from_str( "0 0 30 2 MON" ).unwrap();
+
+        assert!( c.is_satisfiable() );
+
+        let c = c.with_day_matching( CronDayMatching::Both );
+
+        assert!( !c.is_satisfiable() );
+        assert_eq!( c.get_next_occurrence( Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap() ), None );
+
+        let c = CronSchedule::from_str( "0 0 0 1 1 * 2030" ).unwrap();
+
+        assert_eq!( c.get_next_occurrence( Utc.with_ymd_and_hms(2030, 6, 1, 0, 0, 0).unwrap() ), None );
+        assert_eq!( c.get_next_occurrence( Utc.with_ymd_and_hms(2099, 12, 31, 23, 59, 59).unwrap() ), None );
+    }
+
+    #[test]
+    fn should_check_each_calendar_of_the_year_field_once(){
+        assert!( !CronSchedule::from_str( "0 0 0 29 2 MON 2030-2031" ).unwrap().with_day_matching( CronDayMatching::Both ).is_satisfiable() );
+        assert!( CronSchedule::from_str( "0 0 0 29 2 MON 2030-2099" ).unwrap().with_day_matching( CronDayMatching::Both ).is_satisfiable() );
+        assert!( !CronSchedule::from_str( "0 0 0 30 2 MON *" ).unwrap().with_day_matching( CronDayMatching::Both ).is_satisfiable() );
+        assert!( CronSchedule::from_str( "0 0 0 13 * FRI *" ).unwrap().with_day_matching( CronDayMatching::Both ).is_satisfiable() );
+    }
+
+    #[test]
+    fn should_return_previous_occurrence(){
+        let c = CronSchedule::from_str( "30 9 * * *" ).unwrap();
+        let at = Utc.with_ymd_and_hms(2022, 12, 19, 9, 30, 0).unwrap();
+
+        assert_eq!( c.get_previous_occurrence( Utc.with_ymd_and_hms(2022, 12, 19, 10, 0, 0).unwrap() ).unwrap(), at );
+        assert_eq!( c.get_previous_occurrence( at ).unwrap(), Utc.with_ymd_and_hms(2022, 12, 18, 9, 30, 0).unwrap() );
+        assert_eq!( c.get_occurrence_at_or_before( at ).unwrap(), at );
+        assert_eq!( c.get_previous_occurrence( at + chrono::Duration::milliseconds( 1 ) ).unwrap(), at );
+
+        let c = CronSchedule::from_str( "0 0 0 1 1 * *" ).unwrap();
+
+        assert_eq!(
+            c.get_previous_occurrence( Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "59 23 31 12 *" ).unwrap();
+
+        assert_eq!(
+            c.get_previous_occurrence( Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 31, 23, 59, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_return_previous_occurrence_of_special_days(){
+        let previous = |expression: &str, before: DateTime<Utc>| CronSchedule::from_str( expression ).unwrap().get_previous_occurrence( before ).unwrap();
+
+        assert_eq!( previous( "0 0 L * *", Utc.with_ymd_and_hms(2023, 3, 15, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap() );
+        assert_eq!( previous( "0 12 15W * *", Utc.with_ymd_and_hms(2023, 4, 20, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2023, 4, 14, 12, 0, 0).unwrap() );
+        assert_eq!( previous( "0 12 LW * *", Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2023, 4, 28, 12, 0, 0).unwrap() );
+        assert_eq!( previous( "0 9 * * 5L", Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2022, 12, 30, 9, 0, 0).unwrap() );
+        assert_eq!( previous( "0 9 * * 2#2", Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2022, 12, 13, 9, 0, 0).unwrap() );
+        assert_eq!( previous( "0 0 13 * FRI", Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2023, 1, 13, 0, 0, 0).unwrap() );
+        assert_eq!( previous( "0 0 13 * SAT", Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap() ), Utc.with_ymd_and_hms(2023, 1, 14, 0, 0, 0).unwrap() );
+    }
+
+    #[test]
+    fn should_return_none_when_schedule_never_occurred(){
+        let c = CronSchedule::from_str( "0 0 0 1 1 * 2030" ).unwrap();
+
+        assert_eq!( c.get_previous_occurrence( Utc.with_ymd_and_hms(2029, 6, 1, 0, 0, 0).unwrap() ), None );
+        assert_eq!( c.get_previous_occurrence( Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap() ), None );
+        assert_eq!( CronSchedule::from_nickname( CronNickname::Reboot ).unwrap().get_previous_occurrence( Utc::now() ), None );
+    }
+
+    #[test]
+    fn should_return_previous_occurrence_across_daylight_saving(){
+        let c = CronSchedule::from_str( "30 2 * * *" ).unwrap().with_time_zone( Tz::America__New_York );
+
+        assert_eq!(
+            c.get_previous_occurrence( Utc.with_ymd_and_hms(2023, 3, 13, 0, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 11, 7, 30, 0).unwrap()
+        );
+
+        let shifted = CronDstPolicy { gap: CronGapPolicy::Shifted, ..CronDstPolicy::default() };
+
+        assert_eq!(
+            c.with_dst_policy( shifted ).get_previous_occurrence( Utc.with_ymd_and_hms(2023, 3, 13, 0, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 12, 7, 0, 0).unwrap()
+        );
+
+        let c = CronSchedule::from_str( "30 1 * * *" ).unwrap().with_time_zone( Tz::America__New_York );
+
+        assert_eq!(
+            c.get_previous_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 7, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 5, 5, 30, 0).unwrap()
+        );
+
+        let twice = CronDstPolicy { overlap: CronOverlapPolicy::Twice, ..CronDstPolicy::default() };
+
+        assert_eq!(
+            c.with_dst_policy( twice ).get_previous_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 7, 0, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 5, 6, 30, 0).unwrap()
+        );
+
+        // Ending in the second pass, a later wall time has already happened in the first pass
+        let c = CronSchedule::from_str( "50 1 * * *" ).unwrap().with_time_zone( Tz::America__New_York );
+
+        assert_eq!(
+            c.get_previous_occurrence( Utc.with_ymd_and_hms(2023, 11, 5, 6, 10, 0).unwrap() ).unwrap(),
+            Utc.with_ymd_and_hms(2023, 11, 5, 5, 50, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn previous_occurrence_should_be_the_last_matching_minute(){
+        let expressions = [ "*/7 */5 * * *", "0 0 1,15 * MON", "30 22-2 * * FRI-MON", "0 12 L * *", "0 9 * * 2#2", "45 23 LW * *" ];
+        let mut seed: i64 = 11;
+
+        for expression in expressions {
+            let c = CronSchedule::from_str( expression ).unwrap();
+
+            for _ in 0..10 {
+                seed = ( seed * 1103515245 + 12345 ) % 2147483648;
+
+                let end = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::minutes( seed % ( 366 * 24 * 60 ) );
+                let previous = c.get_previous_occurrence( end ).unwrap();
+
+                assert!( c.check_date( &previous ), "{expression} before {end} gave {previous}" );
+                assert!( c.get_next_occurrence( previous ).unwrap() >= end, "{expression} before {end} gave {previous}" );
+
+                let mut minute = previous + chrono::Duration::minutes( 1 );
+
+                while minute < end {
+                    assert!( !c.check_date( &minute ), "{expression} before {end} skipped {minute}" );
+                    minute += chrono::Duration::minutes( 1 );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_reject_malformed_items(){
+        let error = CronSchedule::from_str( "abc/5x * * * *" ).unwrap_err();
+
+        assert!( matches!( error, CronError::InvalidArgument { position: CronPosition::Minute, .. } ) );
+        assert_eq!( error.span(), 0..6 );
+
+        assert!( CronSchedule::from_str( "0 0 x15W * *" ).is_err() );
+        assert!( CronSchedule::from_str( "0 0 * * MON-FRI/2x" ).is_err() );
+        assert!( CronSchedule::from_str( "0 0 * * mon-fri/2" ).is_ok() );
+    }
+
+    #[test]
+    fn should_report_error_position_and_span(){
+        let error = CronSchedule::from_str( "0  25 * * *" ).unwrap_err();
+
+        assert_eq!( error.position(), Some( CronPosition::Hour ) );
+        assert_eq!( error.token(), "25" );
+        assert_eq!( error.span(), 3..5 );
+        assert_eq!( error.bounds(), Some( ( 0, 23 ) ) );
+
+        let error = CronSchedule::from_str( "* * 1,15,40-42 * *" ).unwrap_err();
+
+        assert_eq!( error.position(), Some( CronPosition::DayOfMonth ) );
+        assert_eq!( error.token(), "42" );
+        assert_eq!( error.span(), 12..14 );
+        assert_eq!( error.bounds(), Some( ( 1, 31 ) ) );
+
+        let error = CronSchedule::from_str( "* * * * MON#6" ).unwrap_err();
+
+        assert_eq!( error.token(), "6" );
+        assert_eq!( error.span(), 12..13 );
+        assert_eq!( error.bounds(), Some( ( 1, 5 ) ) );
+
+        let error = CronSchedule::from_str( "* * * * 1,8" ).unwrap_err();
+
+        assert_eq!( error.token(), "8" );
+        assert_eq!( error.bounds(), Some( ( 0, 7 ) ) );
+        assert!( error.to_string().contains( "between 0 and 7" ) );
+    }
+
+    #[test]
+    fn should_report_error_kind(){
+        let error = CronSchedule::from_str( "* 5-5 * * *" ).unwrap_err();
+
+        assert!( matches!( error, CronError::InvalidRange { position: CronPosition::Hour, .. } ) );
+        assert_eq!( error.span(), 2..5 );
+
+        let error = CronSchedule::from_str( "* * * FOO *" ).unwrap_err();
+
+        assert!( matches!( error, CronError::InvalidArgument { position: CronPosition::Month, .. } ) );
+        assert_eq!( error.token(), "FOO" );
+        assert_eq!( error.bounds(), None );
+
+        let error = CronSchedule::from_str( "* * *" ).unwrap_err();
+
+        assert!( matches!( error, CronError::InvalidFieldCount { found: 3, .. } ) );
+        assert_eq!( error.position(), None );
+
+        let error = CronSchedule::new( "*", "*", "0-5", "*", "*" ).unwrap_err();
+
+        assert_eq!( error.token(), "0" );
+        assert_eq!( error.span(), 4..5 );
+    }
+
+    #[test]
+    fn should_only_parse_w_in_day_of_month(){
+        // let did_error = match CronSchedule::new( "5W", "*", "*", "*", "*" ) {
+        //     Err(_e) => true,
+        //     _ => false
+        // };
+        
+        // assert!( did_error );
+
+        // let did_error = match CronSchedule::new( "*", "5W", "*", "*", "*" ) {
+        //     Err(_e) => true,
+        //     _ => false
+        // };
+        
+        // assert!( did_error );
+
+        // let did_error = match CronSchedule::new( "*", "*", "*", "5W", "*" ) {
+        //     Err(_e) => true,
+        //     _ => false
+        // };
+        
+        // assert!( did_error );
+
+        // let did_error = match CronSchedule::new( "*", "*", "*", "*", "5W" ) {
+        //     Err(_e) => true,
+        //     _ => false
+        // };
+        
+        // assert!( did_error );
+
+        let did_error = match CronSchedule::new( "*", "*", "5W", "*", "*" ) {
+            Err(_e) => true,
+            _ => false
+        };
+        
+        assert!( !did_error );
+    }
+}
+
+
+
+macro_rules! validate_number {
+    ($n:ident between $min:literal and $max:literal) => {
+        {
+            if !($min..=$max).contains( &$n ) {
+                return Err( FieldError::OutOfBounds { value: $n, min: $min, max: $max } );
+            }
+        }
+    };
+}
+
+macro_rules! validate_range {
+    ($a:ident to $b:ident between $min:literal and $max:literal) => {
+        {
+            if !($min..=$max).contains( &$b ) {
+                return Err( FieldError::OutOfBounds { value: $b, min: $min, max: $max } );
+            }
+            else if !($min..=$max).contains( &$a ) {
+                return Err( FieldError::OutOfBounds { value: $a, min: $min, max: $max } );
+            }
+            else if $a == $b {
+                return Err( FieldError::InvalidRange );
+            }
+        }
+    };
+}
+
+
+
+const MAX_GAP_MINUTES: u32 = 24 * 60;
+
+#[derive(Debug)]
+struct CronArg{
+    position: CronPosition,
+    command: CronCommand,
+    bitset: CronBitset
+}
+
+impl CronArg {
+    fn new( position: CronPosition, command: CronCommand ) -> CronArg {
+        let bitset = CronBitset::compile( &position, &command );
+
+        CronArg{ position, command, bitset }
+    }
+
+    /// Parses a field starting at `offset` in the expression, list items are parsed one at a time so errors point at the item
+    fn parse( position: CronPosition, ( offset, field ): ( usize, &str ), dialect: &CronDialect ) -> Result<CronArg, CronError> {
+        let mut items = vec![];
+        let mut item_offset = offset;
+
+        for item in field.split( ',' ) {
+            items.push( CronArg::parse_item( position, item, dialect ).map_err( |e| e.at( position, item, item_offset ) )? );
+            item_offset += item.len() + 1;
+        }
+
+        let command = match items.len() {
+            1 => items.remove( 0 ),
+            _ => CronCommand::List( items )
+        };
+
+        Ok( CronArg::new( position, command ) )
+    }
+
+    fn parse_item( position: CronPosition, item: &str, dialect: &CronDialect ) -> Result<CronCommand, FieldError> {
+        let mut command = parse_field( item, &|name| position.resolve_name( name, dialect ) )?;
+
+        if let CronPosition::DayOfWeek = position {
+            command = match dialect {
+                CronDialect::Standard => command.normalize_sunday(),
+                CronDialect::Quartz => {
+                    let out_of_bounds = Cell::new( 0 );
+
+                    command.map_weekdays( &|n| {
+                        if !( 1..=7 ).contains( &n ) {
+                            out_of_bounds.set( n );
+                        }
+
+                        ( 1..=7 ).contains( &n ).then( || n - 1 )
+                    })
+                    .ok_or_else( || FieldError::OutOfBounds { value: out_of_bounds.get(), min: 1, max: 7 } )?
+                },
+            };
+        }
+
+        if let ( CronCommand::NoSpecific, CronDialect::Standard ) = ( &command, dialect ) {
+            return Err( FieldError::InvalidArgument );
+        }
+
+        CronArg::validate( &position, &command )?;
+
+        Ok( command )
+    }
+
+    fn validate( position: &CronPosition, command: &CronCommand ) -> Result<(), FieldError> {
+        match *command {
+            CronCommand::Number(n) => {
+                match position {
+                    CronPosition::Second => validate_number!( n between 0 and 59 ),
+                    CronPosition::Minute => validate_number!( n between 0 and 59 ),
+                    CronPosition::Hour => validate_number!( n between 0 and 23 ),
+                    CronPosition::DayOfMonth => validate_number!( n between 1 and 31 ),
+                    CronPosition::Month => validate_number!( n between 1 and 12 ),
+                    // 7 is read as Sunday before validating, but is still a value the field accepts
+                    CronPosition::DayOfWeek => validate_number!( n between 0 and 7 ),
+                    CronPosition::Year => validate_number!( n between 1970 and 2099 ),
+                }
+            },
+            CronCommand::Range(min,max) => {
+                match position {
+                    CronPosition::Second => validate_range!( min to max between 0 and 59 ),
+                    CronPosition::Minute => validate_range!( min to max between 0 and 59 ),
+                    CronPosition::Hour => validate_range!( min to max between 0 and 23 ),
+                    CronPosition::DayOfMonth => validate_range!( min to max between 1 and 31 ),
+                    CronPosition::Month => validate_range!( min to max between 1 and 12 ),
+                    CronPosition::DayOfWeek => validate_range!( min to max between 0 and 7 ),
+                    CronPosition::Year => validate_range!( min to max between 1970 and 2099 ),
+                }
+            },
+            CronCommand::W(n) => {
+                match position {
+                    CronPosition::DayOfMonth => validate_number!( n between 1 and 31 ),
+                    _ => {
+                        return Err( FieldError::InvalidArgument );
+                    },
+                }
+            },
+            CronCommand::Step( ref base, step ) => {
+                match *base {
+                    StepBase::Start(start) => CronArg::validate( position, &CronCommand::Number( start ) )?,
+                    StepBase::Range( start, end ) => CronArg::validate( position, &CronCommand::Range( start, end ) )?,
+                    StepBase::Multiple | StepBase::Asterisk => {}
+                }
+
+                match position {
+                    CronPosition::Second => validate_number!( step between 1 and 59 ),
+                    CronPosition::Minute => validate_number!( step between 1 and 59 ),
+                    CronPosition::Hour => validate_number!( step between 1 and 23 ),
+                    CronPosition::DayOfMonth => validate_number!( step between 1 and 31 ),
+                    CronPosition::Month => validate_number!( step between 1 and 12 ),
+                    CronPosition::DayOfWeek => validate_number!( step between 1 and 6 ),
+                    CronPosition::Year => validate_number!( step between 1 and 129 ),
+                }
+            },
+            CronCommand::LastDay(offset) => {
+                match position {
+                    CronPosition::DayOfMonth => {
+                        validate_number!( offset between 0 and 30 );
+                    },
+                    _ => {
+                        return Err( FieldError::InvalidArgument );
+                    },
+                }
+            },
+            CronCommand::LastWeekday => {
+                match position {
+                    CronPosition::DayOfMonth => {},
+                    _ => {
+                        return Err( FieldError::InvalidArgument );
+                    },
+                }
+            },
+            CronCommand::NoSpecific => {
+                match position {
+                    CronPosition::DayOfMonth | CronPosition::DayOfWeek => {},
+                    _ => {
+                        return Err( FieldError::InvalidArgument );
+                    },
+                }
+            },
+            CronCommand::L(weekday) => {
+                match position {
+                    CronPosition::DayOfWeek => validate_number!( weekday between 0 and 7 ),
+                    _ => {
+                        return Err( FieldError::InvalidArgument );
+                    },
+                }
+            },
+            CronCommand::Nth( weekday, n ) => {
+                match position {
+                    CronPosition::DayOfWeek => {
+                        validate_number!( weekday between 0 and 7 );
+
+                        validate_number!( n between 1 and 5 );
+                    },
+                    _ => {
+                        return Err( FieldError::InvalidArgument );
+                    },
+                }
+            },
+            CronCommand::List(ref items) => {
+                for item in items {
+                    CronArg::validate( position, item )?;
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn check_date( &self, date: &NaiveDateTime ) -> bool {
+        self.bitset.contains( self.position.get_value_from_date( date ) ) || self.bitset.matches_day( &date.date() )
+    }
+}
+
+#[derive(Debug)]
+pub struct CronSchedule {
+    cron_second: Option<CronArg>,
+    cron_minute: CronArg,
+    cron_hour: CronArg,
+    cron_day_of_month: CronArg,
+    cron_month: CronArg,
+    cron_day_of_week: CronArg,
+    cron_year: Option<CronArg>,
+    nickname: Option<CronNickname>,
+    dialect: CronDialect,
+    day_matching: CronDayMatching,
+    time_zone: Tz,
+    dst_policy: CronDstPolicy
+}
+
+
+impl CronSchedule {
+
+    /// Error spans are given as if the fields were written out separated by single spaces
+    pub fn new( minute: &str, hour: &str, day_of_month: &str, month: &str, day_of_week: &str ) -> Result<CronSchedule, CronError> {
+        CronSchedule::from_fields( &with_offsets( &[ minute, hour, day_of_month, month, day_of_week ] ), CronDialect::Standard )
+    }
+
+    /// Parses five fields, or six and seven with leading seconds and trailing year, each paired with where it starts
+    fn from_fields( all_fields: &[( usize, &str )], dialect: CronDialect ) -> Result<CronSchedule, CronError> {
+        let ( second, fields, year ) = match all_fields.len() {
+            7 => ( Some( all_fields[ 0 ] ), &all_fields[ 1..6 ], Some( all_fields[ 6 ] ) ),
+            6 => ( Some( all_fields[ 0 ] ), &all_fields[ 1.. ], None ),
+            _ => ( None, all_fields, None )
+        };
+
+        let schedule = CronSchedule {
+            cron_second: second.map( |second| CronArg::parse( CronPosition::Second, second, &dialect ) ).transpose()?,
+            cron_minute: CronArg::parse( CronPosition::Minute, fields[ 0 ], &dialect )?,
+            cron_hour: CronArg::parse( CronPosition::Hour, fields[ 1 ], &dialect )?,
+            cron_day_of_month: CronArg::parse( CronPosition::DayOfMonth, fields[ 2 ], &dialect )?,
+            cron_month: CronArg::parse( CronPosition::Month, fields[ 3 ], &dialect )?,
+            cron_day_of_week: CronArg::parse( CronPosition::DayOfWeek, fields[ 4 ], &dialect )?,
+            cron_year: year.map( |year| CronArg::parse( CronPosition::Year, year, &dialect ) ).transpose()?,
+            nickname: None,
+            dialect,
+            day_matching: CronDayMatching::default(),
+            time_zone: Tz::UTC,
+            dst_policy: CronDstPolicy::default(),
+        };
+
+        if !schedule.is_satisfiable() {
+            let ( first_offset, _ ) = all_fields[ 0 ];
+            let ( last_offset, last ) = all_fields[ all_fields.len() - 1 ];
+
+            return Err( CronError::Unsatisfiable {
+                token: all_fields.iter().map( |( _, field )| *field ).collect::<Vec<&str>>().join( " " ),
+                span: first_offset..last_offset + last.len(),
+            });
+        }
+
+        if let CronDialect::Quartz = dialect {
+            let day_of_month_unspecified = matches!( schedule.cron_day_of_month.command, CronCommand::NoSpecific );
+            let day_of_week_unspecified = matches!( schedule.cron_day_of_week.command, CronCommand::NoSpecific );
+
+            if day_of_month_unspecified == day_of_week_unspecified {
+                let ( offset, day_of_week ) = fields[ 4 ];
+
+                return Err( CronError::InvalidQuartzDays {
+                    day_of_month: fields[ 2 ].1.to_owned(),
+                    token: day_of_week.to_owned(),
+                    span: offset..offset + day_of_week.len(),
+                });
+            }
+        }
+
+        Ok( schedule )
+    }
+
+    /// Parses an expression written in the given dialect, Quartz expressions need six or seven fields
+    pub fn parse_with_dialect( cron_string: &str, dialect: CronDialect ) -> Result<CronSchedule, CronError> {
+        if let ( Some( nickname ), CronDialect::Standard ) = ( CronNickname::from_name( cron_string.trim() ), dialect ) {
+            return CronSchedule::from_nickname( nickname );
+        }
+
+        let parts = split_fields( cron_string );
+
+        let field_counts = match dialect {
+            CronDialect::Standard => 5..=7,
+            CronDialect::Quartz => 6..=7,
+        };
+
+        if !field_counts.contains( &parts.len() ) {
+            return Err( CronError::InvalidFieldCount {
+                token: cron_string.to_owned(),
+                span: 0..cron_string.len(),
+                min: *field_counts.start(),
+                max: *field_counts.end(),
+                found: parts.len(),
+            });
+        }
+
+        CronSchedule::from_fields( &parts, dialect )
+    }
+
+    pub fn dialect( &self ) -> CronDialect {
+        self.dialect
+    }
+
+    /// Chooses how the day of month and day of week fields combine when both are restricted
+    pub fn with_day_matching( self, day_matching: CronDayMatching ) -> CronSchedule {
+        CronSchedule { day_matching, ..self }
+    }
+
+    pub fn day_matching( &self ) -> CronDayMatching {
+        self.day_matching
+    }
+
+    /// Evaluates the schedule on the wall clock of the given zone, schedules are in UTC unless set
+    pub fn with_time_zone( self, time_zone: Tz ) -> CronSchedule {
+        CronSchedule { time_zone, ..self }
+    }
+
+    pub fn time_zone( &self ) -> Tz {
+        self.time_zone
+    }
+
+    /// Chooses what happens to occurrences whose wall time is skipped or repeated by a daylight saving change
+    pub fn with_dst_policy( self, dst_policy: CronDstPolicy ) -> CronSchedule {
+        CronSchedule { dst_policy, ..self }
+    }
+
+    pub fn dst_policy( &self ) -> CronDstPolicy {
+        self.dst_policy
+    }
+
+    /// Whether a date has to match only one of the day of month and day of week fields
+    pub(crate) fn matches_either_day( &self ) -> bool {
+        self.day_matching == CronDayMatching::Either
+        && !self.cron_day_of_month.command.is_unrestricted()
+        && !self.cron_day_of_week.command.is_unrestricted()
+    }
+
+    /// The schedule written the one canonical way, so two spellings of the same schedule format the same.
+    ///
+    /// Lists are sorted without repeats, runs become ranges, names become numbers, fields matching every value become `*`,
+    /// seconds of 0 and every year are left out and nicknames other than `@reboot` are written out
+    pub fn normalize( &self ) -> CronSchedule {
+        let either = self.matches_either_day();
+        let canonical = |arg: &CronArg| arg.bitset.canonical( &arg.position, false );
+        let every_day = |arg: &CronArg| matches!( canonical( arg ), CronCommand::Asterisk );
+
+        // A day field left unrestricted has to stay that way, or it would start matching either day instead of both
+        let day = |arg: &CronArg| match ( either, &arg.command ) {
+            ( true, _ ) => arg.bitset.canonical( &arg.position, true ),
+            ( false, command @ ( CronCommand::NoSpecific | CronCommand::Step( StepBase::Asterisk, 2.. ) ) ) => command.clone(),
+            ( false, _ ) => canonical( arg )
+        };
+
+        // When either day may match and one of them matches every day, so does the schedule
+        let ( day_of_month, day_of_week ) = match either && ( every_day( &self.cron_day_of_month ) || every_day( &self.cron_day_of_week ) ) {
+            true => ( CronCommand::Asterisk, CronCommand::Asterisk ),
+            false => ( day( &self.cron_day_of_month ), day( &self.cron_day_of_week ) )
+        };
+
+        let year = self.cron_year.as_ref().map( canonical ).filter( |year| !matches!( year, CronCommand::Asterisk ) );
+        let second = self.cron_second.as_ref().map( canonical ).filter( |second| year.is_some() || !matches!( second, CronCommand::Number( 0 ) ) );
+
+        CronSchedule {
+            cron_second: second.map( |command| CronArg::new( CronPosition::Second, command ) ),
+            cron_minute: CronArg::new( CronPosition::Minute, canonical( &self.cron_minute ) ),
+            cron_hour: CronArg::new( CronPosition::Hour, canonical( &self.cron_hour ) ),
+            cron_day_of_month: CronArg::new( CronPosition::DayOfMonth, day_of_month ),
+            cron_month: CronArg::new( CronPosition::Month, canonical( &self.cron_month ) ),
+            cron_day_of_week: CronArg::new( CronPosition::DayOfWeek, day_of_week ),
+            cron_year: year.map( |command| CronArg::new( CronPosition::Year, command ) ),
+            nickname: self.is_reboot().then_some( CronNickname::Reboot ),
+            dialect: self.dialect,
+            day_matching: self.day_matching,
+            time_zone: self.time_zone,
+            dst_policy: self.dst_policy,
+        }
+    }
+
+    /// Formats the schedule in the given dialect, Quartz output always has seconds and a `?` day field.
+    ///
+    /// `None` when there is no Quartz form, as Quartz cannot restrict both the day of month and the day of week
+    pub fn to_dialect_string( &self, dialect: &CronDialect ) -> Option<String> {
+        if let ( Some( nickname ), CronDialect::Standard ) = ( &self.nickname, dialect ) {
+            return Some( nickname.to_string() );
+        }
+
+        let mut day_of_month = self.cron_day_of_month.command.clone();
+        let mut day_of_week = self.cron_day_of_week.command.clone();
+
+        match dialect {
+            CronDialect::Standard => {
+                if let CronCommand::NoSpecific = day_of_month {
+                    day_of_month = CronCommand::Asterisk;
+                }
+
+                if let CronCommand::NoSpecific = day_of_week {
+                    day_of_week = CronCommand::Asterisk;
+                }
+            },
+            CronDialect::Quartz => {
+                if let Some( quartz_day_of_week ) = day_of_week.map_weekdays( &|n| Some( n + 1 ) ) {
+                    day_of_week = quartz_day_of_week;
+                }
+
+                match ( &day_of_month, &day_of_week ) {
+                    ( CronCommand::NoSpecific, _ ) | ( _, CronCommand::NoSpecific ) => {},
+                    ( _, CronCommand::Asterisk ) => day_of_week = CronCommand::NoSpecific,
+                    ( CronCommand::Asterisk, _ ) => day_of_month = CronCommand::NoSpecific,
+                    _ => return None
+                }
+            }
+        }
+
+        let mut parts = vec![];
+
+        match ( &self.cron_second, dialect ) {
+            ( Some( second ), _ ) => parts.push( second.command.to_string() ),
+            ( None, CronDialect::Quartz ) => parts.push( "0".to_owned() ),
+            ( None, CronDialect::Standard ) => {}
+        }
+
+        parts.push( self.cron_minute.command.to_string() );
+        parts.push( self.cron_hour.command.to_string() );
+        parts.push( day_of_month.to_string() );
+        parts.push( self.cron_month.command.to_string() );
+        parts.push( day_of_week.to_string() );
+
+        if let Some( year ) = &self.cron_year {
+            parts.push( year.command.to_string() );
+        }
+
+        Some( parts.join( " " ) )
+    }
+
+    /// Builds the schedule a nickname such as `@daily` stands for, keeping the nickname for display
+    pub fn from_nickname( nickname: CronNickname ) -> Result<CronSchedule, CronError> {
+        // `@reboot` has no calendar fields, the asterisks are never consulted
+        let expression = nickname.expression().unwrap_or( "* * * * *" );
+        let parts: Vec<&str> = expression.split( ' ' ).collect();
+
+        Ok(CronSchedule {
+            nickname: Some( nickname ),
+            ..CronSchedule::new( parts[ 0 ], parts[ 1 ], parts[ 2 ], parts[ 3 ], parts[ 4 ] )?
+        })
+    }
+
+    /// Starts a schedule built from typed values rather than a string, such as
+    /// `CronSchedule::builder().minute( 0 ).hours( 9..=17 ).weekdays( [ DayOfWeek::Monday, DayOfWeek::Friday ] ).build()`
+    pub fn builder() -> CronBuilder {
+        CronBuilder::default()
+    }
+
+    /// Parses a phrase such as "every weekday at 9:30am" or "first Monday of every month at noon",
+    /// see `natural::parse_english` for the grammar. Errors point at the words that were not understood
+    /// or that have no cron equivalent
+    pub fn from_english( phrase: &str ) -> Result<CronSchedule, CronError> {
+        natural::parse_english( phrase )
+    }
+
+    /// The schedule in plain English, such as "At 09:00 on every weekday in January through March"
+    pub fn describe( &self ) -> String {
+        describe::describe( self, &CronEnglish )
+    }
+
+    /// The schedule described in the given locale
+    pub fn describe_in( &self, locale: &dyn CronLocale ) -> String {
+        describe::describe( self, locale )
+    }
+
+    pub fn nickname( &self ) -> Option<&CronNickname> {
+        self.nickname.as_ref()
+    }
+
+    /// `@reboot` schedules run once at startup and never match a calendar date
+    pub fn is_reboot( &self ) -> bool {
+        matches!( self.nickname, Some( CronNickname::Reboot ) )
+    }
+
+    /// Six field form used by Quartz and Spring with a leading seconds field
+    pub fn new_with_seconds( second: &str, minute: &str, hour: &str, day_of_month: &str, month: &str, day_of_week: &str ) -> Result<CronSchedule, CronError> {
+        CronSchedule::from_fields( &with_offsets( &[ second, minute, hour, day_of_month, month, day_of_week ] ), CronDialect::Standard )
+    }
+
+    /// Seven field Quartz form with a leading seconds field and a trailing year field
+    pub fn new_with_year( second: &str, minute: &str, hour: &str, day_of_month: &str, month: &str, day_of_week: &str, year: &str ) -> Result<CronSchedule, CronError> {
+        CronSchedule::from_fields( &with_offsets( &[ second, minute, hour, day_of_month, month, day_of_week, year ] ), CronDialect::Standard )
+    }
+
+    /// Whether the instant, read on the wall clock of the schedule's zone, is an occurrence
+    pub fn check_date<Z: TimeZone>( &self, date: &DateTime<Z> ) -> bool {
+        self.check_local( &date.with_timezone( &self.time_zone ).naive_local() )
+    }
+
+    fn check_local( &self, date: &NaiveDateTime ) -> bool {
+        !self.is_reboot() && self.first_mismatch( date ).is_none()
+    }
+
+    fn check_day( &self, date: &NaiveDateTime ) -> bool {
+        if self.matches_either_day() {
+            self.cron_day_of_month.check_date( date ) || self.cron_day_of_week.check_date( date )
+        }
+        else {
+            self.cron_day_of_month.check_date( date ) && self.cron_day_of_week.check_date( date )
+        }
+    }
+
+    /// The next occurrence in the zone of the start instant, `None` for `@reboot` and when nothing
+    /// occurs before the end of the last year a schedule can name
+    pub fn get_next_occurrence<Z: TimeZone>( &self, start: DateTime<Z> ) -> Option<DateTime<Z>> {
+        if self.is_reboot() {
+            return None;
+        }
+
+        self.next_zoned_occurrence( &start.with_timezone( &self.time_zone ) )
+            .map( |next| next.with_timezone( &start.timezone() ) )
+    }
+
+    /// The first instant after the start whose wall time matches, following the daylight saving policy
+    fn next_zoned_occurrence( &self, start: &DateTime<Tz> ) -> Option<DateTime<Tz>> {
+        let local = start.naive_local();
+        let next = self.next_zoned_occurrence_from( start, local, false );
+
+        // In the first pass of a repeated hour the second pass of earlier wall times is still ahead
+        if let ( CronOverlapPolicy::Twice, LocalResult::Ambiguous( first, second ) ) = ( self.dst_policy.overlap, self.time_zone.from_local_datetime( &local ) ) {
+            if first == *start {
+                let repeated = first.offset().fix().local_minus_utc() - second.offset().fix().local_minus_utc();
+                let second_pass = self.next_zoned_occurrence_from( start, local - chrono::Duration::seconds( repeated.into() ), true );
+
+                return [ next, second_pass ].into_iter().flatten().min();
+            }
+        }
+
+        next
+    }
+
+    /// Searches matching wall times after `from` for one with an instant after the start,
+    /// only looking at the second pass of a repeated hour when asked to
+    fn next_zoned_occurrence_from( &self, start: &DateTime<Tz>, from: NaiveDateTime, second_pass_only: bool ) -> Option<DateTime<Tz>> {
+        let mut local = from;
+
+        loop {
+            local = self.next_local_occurrence( local )?;
+
+            let next = match ( self.time_zone.from_local_datetime( &local ), second_pass_only ) {
+                ( LocalResult::Ambiguous( _, second ), true ) => Some( second ),
+                ( _, true ) if local < start.naive_local() => None,
+                ( _, true ) => return None,
+                ( LocalResult::Single( next ), false ) => Some( next ),
+                ( LocalResult::Ambiguous( first, _ ), false ) if first > *start => Some( first ),
+                ( LocalResult::Ambiguous( _, second ), false ) => match self.dst_policy.overlap {
+                    CronOverlapPolicy::Once => None,
+                    CronOverlapPolicy::Twice => Some( second ),
+                },
+                ( LocalResult::None, false ) => match self.dst_policy.gap {
+                    CronGapPolicy::Skip => None,
+                    CronGapPolicy::Shifted => self.end_of_gap( local ),
+                },
+            };
+
+            if let Some( next ) = next.filter( |next| next > start ) {
+                return Some( next );
+            }
+        }
+    }
+
+    /// The instant the clocks went forward over a wall time that does not exist
+    fn end_of_gap( &self, local: NaiveDateTime ) -> Option<DateTime<Tz>> {
+        // Clocks change on a whole minute, so the first minute after the wall time that exists is when they did
+        let mut wall = local.with_second( 0 )?.with_nanosecond( 0 )?;
+
+        for _ in 0..MAX_GAP_MINUTES {
+            wall += chrono::Duration::minutes( 1 );
+
+            if let Some( end ) = self.time_zone.from_local_datetime( &wall ).earliest() {
+                return Some( end );
+            }
+        }
+
+        None
+    }
+
+    /// The last occurrence strictly before the given instant, in its zone, `None` for `@reboot` and when
+    /// nothing occurred since the start of the first year a schedule can name
+    pub fn get_previous_occurrence<Z: TimeZone>( &self, before: DateTime<Z> ) -> Option<DateTime<Z>> {
+        if self.is_reboot() {
+            return None;
+        }
+
+        self.previous_zoned_occurrence( &before.with_timezone( &self.time_zone ) )
+            .map( |previous| previous.with_timezone( &before.timezone() ) )
+    }
+
+    /// The given instant when it is an occurrence, otherwise the last occurrence before it
+    pub fn get_occurrence_at_or_before<Z: TimeZone>( &self, at: DateTime<Z> ) -> Option<DateTime<Z>> {
+        let just_after = at.clone().checked_add_signed( chrono::Duration::nanoseconds( 1 ) )?;
+
+        self.get_previous_occurrence( just_after )
+            .map( |previous| previous.with_timezone( &at.timezone() ) )
+    }
+
+    /// Every occurrence after the start, in order
+    pub fn occurrences<Z: TimeZone>( &self, start: DateTime<Z> ) -> CronOccurrences<'_, Z> {
+        CronOccurrences::new( self, start, None )
+    }
+
+    /// Every occurrence before the end, from the latest back
+    pub fn occurrences_rev<Z: TimeZone>( &self, end: DateTime<Z> ) -> CronOccurrencesRev<'_, Z> {
+        CronOccurrencesRev::new( self, end )
+    }
+
+    /// The occurrences at or after the start and before the end, in order
+    pub fn occurrences_between<Z: TimeZone>( &self, start: DateTime<Z>, end: DateTime<Z> ) -> CronOccurrences<'_, Z> {
+        let just_before = start.clone().checked_sub_signed( chrono::Duration::nanoseconds( 1 ) );
+
+        match just_before {
+            Some( just_before ) => CronOccurrences::new( self, just_before, Some( end ) ),
+            None => CronOccurrences::new( self, start, Some( end ) )
+        }
+    }
+
+    /// The last instant before the end whose wall time matches, following the daylight saving policy
+    fn previous_zoned_occurrence( &self, end: &DateTime<Tz> ) -> Option<DateTime<Tz>> {
+        let local = end.naive_local();
+        let previous = self.previous_zoned_occurrence_from( end, local, false );
+
+        // In the second pass of a repeated hour the first pass of later wall times has already happened
+        if let LocalResult::Ambiguous( first, second ) = self.time_zone.from_local_datetime( &local ) {
+            if second == *end {
+                let repeated = first.offset().fix().local_minus_utc() - second.offset().fix().local_minus_utc();
+                let first_pass = self.previous_zoned_occurrence_from( end, local + chrono::Duration::seconds( repeated.into() ), true );
+
+                return [ previous, first_pass ].into_iter().flatten().max();
+            }
+        }
+
+        previous
+    }
+
+    /// Searches matching wall times before `from` for one with an instant before the end,
+    /// only looking at the first pass of a repeated hour when asked to
+    fn previous_zoned_occurrence_from( &self, end: &DateTime<Tz>, from: NaiveDateTime, first_pass_only: bool ) -> Option<DateTime<Tz>> {
+        let mut local = from;
+
+        loop {
+            local = self.previous_local_occurrence( local )?;
+
+            let previous = match ( self.time_zone.from_local_datetime( &local ), first_pass_only ) {
+                ( LocalResult::Ambiguous( first, _ ), true ) => Some( first ),
+                ( _, true ) if local > end.naive_local() => None,
+                ( _, true ) => return None,
+                ( LocalResult::Single( previous ), false ) => Some( previous ),
+                ( LocalResult::Ambiguous( first, second ), false ) => match self.dst_policy.overlap {
+                    CronOverlapPolicy::Once => Some( first ),
+                    CronOverlapPolicy::Twice => [ second, first ].into_iter().find( |previous| previous < end ),
+                },
+                ( LocalResult::None, false ) => match self.dst_policy.gap {
+                    CronGapPolicy::Skip => None,
+                    CronGapPolicy::Shifted => self.end_of_gap( local ),
+                },
+            };
+
+            if let Some( previous ) = previous.filter( |previous| previous < end ) {
+                return Some( previous );
+            }
+        }
+    }
+
+    /// The first wall time after the start that matches. The first field that does not match moves on
+    /// to the start of its next value, which resets every field below it, until all of them match
+    fn next_local_occurrence( &self, start: NaiveDateTime ) -> Option<NaiveDateTime> {
+        let resolution = match self.cron_second {
+            Some( _ ) => CronPosition::Second,
+            None => CronPosition::Minute
+        };
+
+        let mut date = resolution.next_start( &start );
+
+        while let Some( current ) = date {
+            if current.year() as u32 > CronPosition::Year.get_max() {
+                break;
+            }
+
+            match self.first_mismatch( &current ) {
+                Some( position ) => date = self.next_candidate( position, &current ),
+                None => return Some( current )
+            }
+        }
+
+        None
+    }
+
+    /// The last wall time before the end that matches. The first field that does not match moves back
+    /// to the last tick before its current value started, so every field below it is at its maximum
+    fn previous_local_occurrence( &self, end: NaiveDateTime ) -> Option<NaiveDateTime> {
+        let ( resolution, tick ) = match self.cron_second {
+            Some( _ ) => ( CronPosition::Second, chrono::Duration::seconds( 1 ) ),
+            None => ( CronPosition::Minute, chrono::Duration::minutes( 1 ) )
+        };
+
+        let mut date = match resolution.start_of( &end ) {
+            Some( start ) if start < end => Some( start ),
+            _ => end.checked_sub_signed( tick )
+        };
+
+        while let Some( current ) = date {
+            if ( current.year() as u32 ) < CronPosition::Year.get_min() {
+                break;
+            }
+
+            match self.first_mismatch( &current ) {
+                Some( position ) => date = self.previous_candidate( position, &current, tick ),
+                None => return Some( current )
+            }
+        }
+
+        None
+    }
+
+    /// The compiled field at a position, `None` for the day fields which are searched a day at a time
+    fn field( &self, position: CronPosition ) -> Option<&CronArg> {
+        match position {
+            CronPosition::Second => self.cron_second.as_ref(),
+            CronPosition::Minute => Some( &self.cron_minute ),
+            CronPosition::Hour => Some( &self.cron_hour ),
+            CronPosition::Month => Some( &self.cron_month ),
+            CronPosition::Year => self.cron_year.as_ref(),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => None,
+        }
+    }
+
+    /// The start of the next value the mismatched field matches, or of the next unit above it when there is none left
+    fn next_candidate( &self, position: CronPosition, date: &NaiveDateTime ) -> Option<NaiveDateTime> {
+        let Some( field ) = self.field( position ) else {
+            return position.next_start( date );
+        };
+
+        match field.bitset.next_from( position.get_value_from_date( date ) + 1 ) {
+            Some( value ) => position.start_at( date, value ),
+            None => position.parent()?.next_start( date )
+        }
+    }
+
+    /// The last tick of the previous value the mismatched field matches, or of the unit above it when there is none left
+    fn previous_candidate( &self, position: CronPosition, date: &NaiveDateTime, tick: chrono::Duration ) -> Option<NaiveDateTime> {
+        let Some( field ) = self.field( position ) else {
+            return position.start_of( date )?.checked_sub_signed( tick );
+        };
+
+        let previous = position.get_value_from_date( date ).checked_sub( 1 ).and_then( |value| field.bitset.previous_from( value ) );
+
+        match previous {
+            Some( value ) => position.next_start( &position.start_at( date, value )? )?.checked_sub_signed( tick ),
+            None => position.parent()?.start_of( date )?.checked_sub_signed( tick )
+        }
+    }
+
+    /// Whether any day matches the date fields, over the years the year field allows or a
+    /// 28 year cycle of weekdays and leap years without one. Fields within a day always have a value
+    pub fn is_satisfiable( &self ) -> bool {
+        let years = match self.cron_year {
+            Some( _ ) => CronPosition::Year.get_min() as i32..=CronPosition::Year.get_max() as i32,
+            None => 2001..=2028
+        };
+
+        // Years of the same length starting on the same weekday share a calendar, so each is checked once
+        let mut calendars = HashSet::new();
+
+        years
+            .filter_map( |year| NaiveDate::from_ymd_opt( year, 1, 1 )?.and_hms_opt( 0, 0, 0 ) )
+            .filter( |january| self.cron_year.as_ref().is_none_or( |year| year.check_date( january ) ) )
+            .filter( |january| calendars.insert( ( days_in_month( january.year(), 2 ), january.weekday() ) ) )
+            .flat_map( |january| ( 1..=12 ).filter_map( move |month| january.with_month( month ) ) )
+            .filter( |first| self.cron_month.check_date( first ) )
+            .any( |first| {
+                ( 0..days_in_month( first.year(), first.month() ) as u64 )
+                    .filter_map( |day| first.checked_add_days( chrono::Days::new( day ) ) )
+                    .any( |date| self.check_day( &date ) )
+            })
+    }
+
+    /// The largest field the wall time does not match, the day fields count as one
+    fn first_mismatch( &self, date: &NaiveDateTime ) -> Option<CronPosition> {
+        if self.cron_year.as_ref().is_some_and( |year| !year.check_date( date ) ) {
+            Some( CronPosition::Year )
+        }
+        else if !self.cron_month.check_date( date ) {
+            Some( CronPosition::Month )
+        }
+        else if !self.check_day( date ) {
+            Some( CronPosition::DayOfMonth )
+        }
+        else if !self.cron_hour.check_date( date ) {
+            Some( CronPosition::Hour )
+        }
+        else if !self.cron_minute.check_date( date ) {
+            Some( CronPosition::Minute )
+        }
+        else if self.cron_second.as_ref().is_some_and( |second| !second.check_date( date ) ) {
+            Some( CronPosition::Second )
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Quartz schedules are parsed with a `?` day field, so only schedules read as standard ones lack a Quartz form
+        let display = self.to_dialect_string( &self.dialect )
+            .or_else( || self.to_dialect_string( &CronDialect::Standard ) )
+            .unwrap_or_default();
+
+        write!( f, "{display}" )
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronError;
+
+    fn from_str( cron_string: &str ) -> Result<CronSchedule, CronError> {
+        CronSchedule::parse_with_dialect( cron_string, CronDialect::Standard )
+    }
+}
+
+/// The whitespace separated fields of an expression, each paired with where it starts
+fn split_fields( cron_string: &str ) -> Vec<( usize, &str )> {
+    let mut fields = vec![];
+    let mut start = None;
+
+    for ( i, c ) in cron_string.char_indices() {
+        match ( c.is_whitespace(), start ) {
+            ( true, Some( field_start ) ) => {
+                fields.push( ( field_start, &cron_string[ field_start..i ] ) );
+                start = None;
+            },
+            ( false, None ) => start = Some( i ),
+            _ => {}
+        }
+    }
+
+    if let Some( field_start ) = start {
+        fields.push( ( field_start, &cron_string[ field_start.. ] ) );
+    }
+
+    fields
+}
+
+/// Pairs each field with where it would start were the fields separated by single spaces
+fn with_offsets<'a>( fields: &[&'a str] ) -> Vec<( usize, &'a str )> {
+    let mut offset = 0;
+
+    fields.iter()
+        .map( |field| {
+            let start = offset;
+            offset += field.len() + 1;
+
+            ( start, *field )
+        })
+        .collect()
+}
@@ -0,0 +1,120 @@
+use crate::position::CronPosition;
+
+mod english;
+mod german;
+mod french;
+mod japanese;
+
+pub use english::CronEnglish;
+pub use german::CronGerman;
+pub use french::CronFrench;
+pub use japanese::CronJapanese;
+
+/// The words and word order used to describe a schedule in a language.
+///
+/// Values are passed as numbers, weekdays count from 0 for Sunday and months from 1 for January,
+/// phrases are passed already put into words by the locale
+pub trait CronLocale {
+    /// A single value of a field, such as "the 15th" for a day of month
+    fn value( &self, position: CronPosition, value: u32 ) -> String;
+
+    /// A range of values of a field, such as "Monday through Friday"
+    fn range( &self, position: CronPosition, start: u32, end: u32 ) -> String;
+
+    /// Items of a list, such as "a, b and c"
+    fn list( &self, items: Vec<String> ) -> String;
+
+    /// The description of `@reboot`
+    fn at_startup( &self ) -> String;
+
+    /// Fixed clock times, already listed
+    fn at_times( &self, times: String ) -> String;
+
+    /// Every second, minute or hour
+    fn every( &self, position: CronPosition ) -> String;
+
+    /// Listed seconds or minutes, `count` is how many items the list has
+    fn at( &self, position: CronPosition, values: String, count: usize ) -> String;
+
+    /// Every n units of the field
+    fn every_n( &self, position: CronPosition, step: u32 ) -> String;
+
+    /// A step that starts from a value
+    fn starting( &self, position: CronPosition, every: String, start: u32 ) -> String;
+
+    /// A step or every unit limited to a range of values
+    fn within( &self, position: CronPosition, every: String, start: u32, end: u32 ) -> String;
+
+    /// A fixed second or minute within every minute or hour
+    fn past( &self, fixed: String, every: String ) -> String;
+
+    /// The hours from the start of one to the end of the other
+    fn hours( &self, start: u32, end: u32 ) -> String;
+
+    /// Listed hours
+    fn during_hours( &self, hours: String ) -> String;
+
+    /// Listed days of the month
+    fn days_of_month( &self, days: String ) -> String;
+
+    /// A single day counted back from the last day of the month, `L-n`, which takes no preposition
+    fn days_before_last_day( &self, days: String ) -> String;
+
+    /// The weekday nearest a day of the month, `W`
+    fn nearest_weekday( &self, day: u32 ) -> String;
+
+    /// The last day of the month or a number of days before it, `L` and `L-n`
+    fn last_day( &self, offset: u32 ) -> String;
+
+    /// The last weekday of the month, `LW`
+    fn last_weekday( &self ) -> String;
+
+    /// Listed days of the week
+    fn weekdays( &self, days: String ) -> String;
+
+    /// Monday to Friday
+    fn monday_to_friday( &self ) -> String;
+
+    /// The nth weekday of the month, `#`
+    fn nth_weekday( &self, n: u32, day: u32 ) -> String;
+
+    /// The last given weekday of the month, `5L`
+    fn last_of_month( &self, day: u32 ) -> String;
+
+    /// Listed months
+    fn months( &self, months: String ) -> String;
+
+    /// Listed years
+    fn years( &self, years: String ) -> String;
+
+    /// Day of month and day of week where either may match
+    fn either( &self, day_of_month: String, day_of_week: String ) -> String;
+
+    /// Day of month and day of week where both must match
+    fn both( &self, day_of_month: String, day_of_week: String ) -> String;
+
+    /// The second, minute and hour clauses that make up the time
+    fn clauses( &self, clauses: Vec<String> ) -> String;
+
+    /// The whole description in the order the language puts it
+    fn sentence( &self, time: String, days: Option<String>, months: Option<String>, years: Option<String> ) -> String;
+}
+
+/// Upper cases the first letter
+fn capitalize( phrase: &str ) -> String {
+    let mut chars = phrase.chars();
+
+    match chars.next() {
+        Some( first ) => first.to_uppercase().chain( chars ).collect(),
+        None => String::new()
+    }
+}
+
+/// "a", "a and b" or "a, b and c" with the given word for and
+fn join( mut items: Vec<String>, and: &str ) -> String {
+    match items.pop() {
+        Some( last ) if items.is_empty() => last,
+        Some( last ) => format!( "{} {and} {last}", items.join( ", " ) ),
+        None => String::new()
+    }
+}
@@ -0,0 +1,237 @@
+use crate::command::{DayOfWeek, Month};
+use crate::position::CronPosition;
+
+use super::{capitalize, join, CronLocale};
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{CronDayMatching, CronNickname, CronSchedule};
+
+    fn describe( expression: &str ) -> String {
+        CronSchedule::from_str( expression ).unwrap().describe()
+    }
+
+    #[test]
+    fn should_describe_fixed_times_as_a_clock(){
+        assert_eq!( describe( "0 9 * 1-3 MON-FRI" ), "At 09:00 on every weekday in January through March" );
+        assert_eq!( describe( "30 0 9,17 * * SAT,SUN" ), "At 09:00:30 and 17:00:30 on Saturday and Sunday" );
+        assert_eq!( describe( "@daily" ), "At 00:00" );
+    }
+
+    #[test]
+    fn should_describe_time_fields(){
+        assert_eq!( describe( "* * * * *" ), "Every minute" );
+        assert_eq!( describe( "* * * * * *" ), "Every second" );
+        assert_eq!( describe( "0 * * * *" ), "At minute 0 past every hour" );
+        assert_eq!( describe( "*/15 9-17 * * MON-FRI" ), "Every 15 minutes, between 09:00 and 17:59 on every weekday" );
+        assert_eq!( describe( "5/10 * * * *" ), "Every 10 minutes starting at minute 5" );
+        assert_eq!( describe( "0,30 8,12-14 * * *" ), "At minutes 0 and 30, during hours 08 and 12 through 14" );
+        assert_eq!( describe( "15 */2 * * *" ), "At minute 15 past every 2 hours" );
+    }
+
+    #[test]
+    fn should_describe_special_days(){
+        assert_eq!( describe( "0 0 1,15,L * *" ), "At 00:00 on the 1st, the 15th and the last day of the month" );
+        assert_eq!( describe( "0 12 15W * *" ), "At 12:00 on the weekday nearest the 15th of the month" );
+        assert_eq!( describe( "0 12 LW * *" ), "At 12:00 on the last weekday of the month" );
+        assert_eq!( describe( "0 12 L-3 * *" ), "At 12:00 3 days before the last day of the month" );
+        assert_eq!( describe( "0 9 * * 5L" ), "At 09:00 on the last Friday of the month" );
+        assert_eq!( describe( "0 9 * * TUE#2" ), "At 09:00 on the 2nd Tuesday of the month" );
+        assert_eq!( describe( "0 9 * * */2" ), "At 09:00 on Sunday, Tuesday, Thursday and Saturday" );
+    }
+
+    #[test]
+    fn should_describe_both_day_fields(){
+        assert_eq!( describe( "0 0 1 * MON" ), "At 00:00 on the 1st of the month or on Monday" );
+
+        let c = CronSchedule::from_str( "0 0 1 * MON" ).unwrap().with_day_matching( CronDayMatching::Both );
+
+        assert_eq!( c.describe(), "At 00:00 on the 1st of the month and on Monday" );
+        assert_eq!( describe( "0 0 */2 * MON" ), "At 00:00 every 2 days and on Monday" );
+    }
+
+    #[test]
+    fn should_describe_months_and_years(){
+        assert_eq!( describe( "0 0 0 1 JAN,JUL * 2030-2035" ), "At 00:00 on the 1st of the month in January and July in 2030 through 2035" );
+        assert_eq!( describe( "0 0 1 */3 *" ), "At 00:00 on the 1st of the month every 3 months" );
+        assert_eq!( CronSchedule::from_nickname( CronNickname::Reboot ).unwrap().describe(), "At startup" );
+    }
+}
+
+/// Describes schedules in English, such as "At 09:00 on every weekday in January through March"
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CronEnglish;
+
+/// 1st, 2nd, 3rd, 4th, 11th, 12th, 13th, 21st and so on
+fn ordinal( n: u32 ) -> String {
+    let suffix = match ( n % 10, n % 100 ) {
+        ( _, 11..=13 ) => "th",
+        ( 1, _ ) => "st",
+        ( 2, _ ) => "nd",
+        ( 3, _ ) => "rd",
+        _ => "th"
+    };
+
+    format!( "{n}{suffix}" )
+}
+
+fn weekday( day: u32 ) -> String {
+    DayOfWeek::from_index( day ).map_or( day.to_string(), |day| day.to_string() )
+}
+
+fn month( month: u32 ) -> String {
+    Month::from_index( month ).map_or( month.to_string(), |month| month.to_string() )
+}
+
+fn unit( position: CronPosition, plural: bool ) -> &'static str {
+    match ( position, plural ) {
+        ( CronPosition::Second, false ) => "second",
+        ( CronPosition::Second, true ) => "seconds",
+        ( CronPosition::Minute, false ) => "minute",
+        ( CronPosition::Minute, true ) => "minutes",
+        ( CronPosition::Hour, false ) => "hour",
+        ( CronPosition::Hour, true ) => "hours",
+        ( CronPosition::DayOfMonth | CronPosition::DayOfWeek, false ) => "day",
+        ( CronPosition::DayOfMonth | CronPosition::DayOfWeek, true ) => "days",
+        ( CronPosition::Month, false ) => "month",
+        ( CronPosition::Month, true ) => "months",
+        ( CronPosition::Year, false ) => "year",
+        ( CronPosition::Year, true ) => "years",
+    }
+}
+
+impl CronLocale for CronEnglish {
+    fn value( &self, position: CronPosition, value: u32 ) -> String {
+        match position {
+            CronPosition::Hour => format!( "{value:02}" ),
+            CronPosition::DayOfMonth => format!( "the {}", ordinal( value ) ),
+            CronPosition::DayOfWeek => weekday( value ),
+            CronPosition::Month => month( value ),
+            _ => value.to_string()
+        }
+    }
+
+    fn range( &self, position: CronPosition, start: u32, end: u32 ) -> String {
+        format!( "{} through {}", self.value( position, start ), self.value( position, end ) )
+    }
+
+    fn list( &self, items: Vec<String> ) -> String {
+        join( items, "and" )
+    }
+
+    fn at_startup( &self ) -> String {
+        "At startup".to_owned()
+    }
+
+    fn at_times( &self, times: String ) -> String {
+        format!( "at {times}" )
+    }
+
+    fn every( &self, position: CronPosition ) -> String {
+        format!( "every {}", unit( position, false ) )
+    }
+
+    fn at( &self, position: CronPosition, values: String, count: usize ) -> String {
+        format!( "at {} {values}", unit( position, count > 1 ) )
+    }
+
+    fn every_n( &self, position: CronPosition, step: u32 ) -> String {
+        format!( "every {step} {}", unit( position, true ) )
+    }
+
+    fn starting( &self, position: CronPosition, every: String, start: u32 ) -> String {
+        match position {
+            CronPosition::Second | CronPosition::Minute => format!( "{every} starting at {} {start}", unit( position, false ) ),
+            CronPosition::Hour => format!( "{every} starting at {start:02}:00" ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => format!( "{every} starting on {}", self.value( position, start ) ),
+            CronPosition::Month | CronPosition::Year => format!( "{every} starting in {}", self.value( position, start ) ),
+        }
+    }
+
+    fn within( &self, position: CronPosition, every: String, start: u32, end: u32 ) -> String {
+        match position {
+            CronPosition::Hour => format!( "{every} {}", self.hours( start, end ) ),
+            position => format!( "{every} from {}", self.range( position, start, end ) )
+        }
+    }
+
+    fn past( &self, fixed: String, every: String ) -> String {
+        format!( "{fixed} past {every}" )
+    }
+
+    fn hours( &self, start: u32, end: u32 ) -> String {
+        format!( "between {start:02}:00 and {end:02}:59" )
+    }
+
+    fn during_hours( &self, hours: String ) -> String {
+        format!( "during hours {hours}" )
+    }
+
+    fn days_of_month( &self, days: String ) -> String {
+        format!( "on {days} of the month" )
+    }
+
+    fn days_before_last_day( &self, days: String ) -> String {
+        format!( "{days} of the month" )
+    }
+
+    fn nearest_weekday( &self, day: u32 ) -> String {
+        format!( "the weekday nearest the {}", ordinal( day ) )
+    }
+
+    fn last_day( &self, offset: u32 ) -> String {
+        match offset {
+            0 => "the last day".to_owned(),
+            1 => "1 day before the last day".to_owned(),
+            offset => format!( "{offset} days before the last day" )
+        }
+    }
+
+    fn last_weekday( &self ) -> String {
+        "the last weekday".to_owned()
+    }
+
+    fn weekdays( &self, days: String ) -> String {
+        format!( "on {days}" )
+    }
+
+    fn monday_to_friday( &self ) -> String {
+        "on every weekday".to_owned()
+    }
+
+    fn nth_weekday( &self, n: u32, day: u32 ) -> String {
+        format!( "the {} {} of the month", ordinal( n ), weekday( day ) )
+    }
+
+    fn last_of_month( &self, day: u32 ) -> String {
+        format!( "the last {} of the month", weekday( day ) )
+    }
+
+    fn months( &self, months: String ) -> String {
+        format!( "in {months}" )
+    }
+
+    fn years( &self, years: String ) -> String {
+        format!( "in {years}" )
+    }
+
+    fn either( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month} or {day_of_week}" )
+    }
+
+    fn both( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month} and {day_of_week}" )
+    }
+
+    fn clauses( &self, clauses: Vec<String> ) -> String {
+        clauses.join( ", " )
+    }
+
+    fn sentence( &self, time: String, days: Option<String>, months: Option<String>, years: Option<String> ) -> String {
+        let phrases: Vec<String> = [ Some( time ), days, months, years ].into_iter().flatten().collect();
+
+        capitalize( &phrases.join( " " ) )
+    }
+}
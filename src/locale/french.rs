@@ -0,0 +1,212 @@
+use crate::position::CronPosition;
+
+use super::{capitalize, join, CronLocale};
+
+/// Describes schedules in French, such as "À 09:00 du lundi au vendredi de janvier à mars"
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CronFrench;
+
+const WEEKDAYS: [&str; 7] = [ "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi" ];
+
+const MONTHS: [&str; 12] = [ "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre" ];
+
+fn weekday( day: u32 ) -> String {
+    WEEKDAYS.get( day as usize ).map_or( day.to_string(), |day| day.to_string() )
+}
+
+fn month( month: u32 ) -> String {
+    MONTHS.get( ( month as usize ).wrapping_sub( 1 ) ).map_or( month.to_string(), |month| month.to_string() )
+}
+
+/// 1er, 2e, 3e and so on
+fn ordinal( n: u32 ) -> String {
+    match n {
+        1 => "1er".to_owned(),
+        n => format!( "{n}e" )
+    }
+}
+
+/// The day of the month as written in a date, only the first is an ordinal
+fn day( n: u32 ) -> String {
+    match n {
+        1 => "1er".to_owned(),
+        n => n.to_string()
+    }
+}
+
+/// `de` before a word, elided before a vowel as in "d'avril"
+fn of( word: &str ) -> String {
+    match word.starts_with( [ 'a', 'e', 'i', 'o', 'u' ] ) {
+        true => format!( "d'{word}" ),
+        false => format!( "de {word}" )
+    }
+}
+
+fn unit( position: CronPosition, plural: bool ) -> &'static str {
+    match ( position, plural ) {
+        ( CronPosition::Second, false ) => "seconde",
+        ( CronPosition::Second, true ) => "secondes",
+        ( CronPosition::Minute, false ) => "minute",
+        ( CronPosition::Minute, true ) => "minutes",
+        ( CronPosition::Hour, false ) => "heure",
+        ( CronPosition::Hour, true ) => "heures",
+        ( CronPosition::DayOfMonth | CronPosition::DayOfWeek, false ) => "jour",
+        ( CronPosition::DayOfMonth | CronPosition::DayOfWeek, true ) => "jours",
+        ( CronPosition::Month, _ ) => "mois",
+        ( CronPosition::Year, false ) => "an",
+        ( CronPosition::Year, true ) => "ans",
+    }
+}
+
+/// Seconds, minutes and hours are feminine, days, months and years masculine
+fn feminine( position: CronPosition ) -> bool {
+    matches!( position, CronPosition::Second | CronPosition::Minute | CronPosition::Hour )
+}
+
+impl CronLocale for CronFrench {
+    fn value( &self, position: CronPosition, value: u32 ) -> String {
+        match position {
+            CronPosition::Hour => format!( "{value:02}" ),
+            CronPosition::DayOfMonth => format!( "le {}", day( value ) ),
+            CronPosition::DayOfWeek => format!( "le {}", weekday( value ) ),
+            CronPosition::Month => format!( "en {}", month( value ) ),
+            CronPosition::Year => format!( "en {value}" ),
+            _ => value.to_string()
+        }
+    }
+
+    fn range( &self, position: CronPosition, start: u32, end: u32 ) -> String {
+        match position {
+            CronPosition::DayOfMonth => format!( "du {} au {}", day( start ), day( end ) ),
+            CronPosition::DayOfWeek => format!( "du {} au {}", weekday( start ), weekday( end ) ),
+            CronPosition::Month => format!( "{} à {}", of( &month( start ) ), month( end ) ),
+            CronPosition::Year => format!( "de {start} à {end}" ),
+            position => format!( "{} à {}", self.value( position, start ), self.value( position, end ) )
+        }
+    }
+
+    fn list( &self, items: Vec<String> ) -> String {
+        join( items, "et" )
+    }
+
+    fn at_startup( &self ) -> String {
+        "Au démarrage".to_owned()
+    }
+
+    fn at_times( &self, times: String ) -> String {
+        format!( "à {times}" )
+    }
+
+    fn every( &self, position: CronPosition ) -> String {
+        format!( "chaque {}", unit( position, false ) )
+    }
+
+    fn at( &self, position: CronPosition, values: String, count: usize ) -> String {
+        match count {
+            1 => format!( "à la {} {values}", unit( position, false ) ),
+            _ => format!( "aux {} {values}", unit( position, true ) )
+        }
+    }
+
+    fn every_n( &self, position: CronPosition, step: u32 ) -> String {
+        let all = if feminine( position ) { "toutes" } else { "tous" };
+
+        format!( "{all} les {step} {}", unit( position, true ) )
+    }
+
+    fn starting( &self, position: CronPosition, every: String, start: u32 ) -> String {
+        match position {
+            CronPosition::Second | CronPosition::Minute => format!( "{every} à partir de la {} {start}", unit( position, false ) ),
+            CronPosition::Hour => format!( "{every} à partir de {start:02}:00" ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => format!( "{every} à partir du {}", day( start ) ),
+            CronPosition::Month => format!( "{every} à partir {}", of( &month( start ) ) ),
+            CronPosition::Year => format!( "{every} à partir de {start}" ),
+        }
+    }
+
+    fn within( &self, position: CronPosition, every: String, start: u32, end: u32 ) -> String {
+        match position {
+            CronPosition::Second | CronPosition::Minute => format!( "{every} de la {} {start} à {end}", unit( position, false ) ),
+            CronPosition::Hour => format!( "{every} {}", self.hours( start, end ) ),
+            position => format!( "{every} {}", self.range( position, start, end ) )
+        }
+    }
+
+    fn past( &self, fixed: String, every: String ) -> String {
+        format!( "{fixed}, {every}" )
+    }
+
+    fn hours( &self, start: u32, end: u32 ) -> String {
+        format!( "entre {start:02}:00 et {end:02}:59" )
+    }
+
+    fn during_hours( &self, hours: String ) -> String {
+        format!( "pendant les heures {hours}" )
+    }
+
+    fn days_of_month( &self, days: String ) -> String {
+        format!( "{days} du mois" )
+    }
+
+    fn days_before_last_day( &self, days: String ) -> String {
+        self.days_of_month( days )
+    }
+
+    fn nearest_weekday( &self, day_of_month: u32 ) -> String {
+        format!( "le jour ouvré le plus proche du {}", day( day_of_month ) )
+    }
+
+    fn last_day( &self, offset: u32 ) -> String {
+        match offset {
+            0 => "le dernier jour".to_owned(),
+            1 => "1 jour avant le dernier jour".to_owned(),
+            offset => format!( "{offset} jours avant le dernier jour" )
+        }
+    }
+
+    fn last_weekday( &self ) -> String {
+        "le dernier jour ouvré".to_owned()
+    }
+
+    fn weekdays( &self, days: String ) -> String {
+        days
+    }
+
+    fn monday_to_friday( &self ) -> String {
+        "du lundi au vendredi".to_owned()
+    }
+
+    fn nth_weekday( &self, n: u32, day: u32 ) -> String {
+        format!( "le {} {} du mois", ordinal( n ), weekday( day ) )
+    }
+
+    fn last_of_month( &self, day: u32 ) -> String {
+        format!( "le dernier {} du mois", weekday( day ) )
+    }
+
+    fn months( &self, months: String ) -> String {
+        months
+    }
+
+    fn years( &self, years: String ) -> String {
+        years
+    }
+
+    fn either( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month} ou {day_of_week}" )
+    }
+
+    fn both( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month} et {day_of_week}" )
+    }
+
+    fn clauses( &self, clauses: Vec<String> ) -> String {
+        clauses.join( ", " )
+    }
+
+    fn sentence( &self, time: String, days: Option<String>, months: Option<String>, years: Option<String> ) -> String {
+        let phrases: Vec<String> = [ Some( time ), days, months, years ].into_iter().flatten().collect();
+
+        capitalize( &phrases.join( " " ) )
+    }
+}
@@ -0,0 +1,180 @@
+use crate::position::CronPosition;
+
+use super::{capitalize, join, CronLocale};
+
+/// Describes schedules in German, such as "Um 09:00 Uhr an jedem Werktag im Januar bis März"
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CronGerman;
+
+const WEEKDAYS: [&str; 7] = [ "Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag" ];
+
+const MONTHS: [&str; 12] = [ "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember" ];
+
+fn weekday( day: u32 ) -> String {
+    WEEKDAYS.get( day as usize ).map_or( day.to_string(), |day| day.to_string() )
+}
+
+fn month( month: u32 ) -> String {
+    MONTHS.get( ( month as usize ).wrapping_sub( 1 ) ).map_or( month.to_string(), |month| month.to_string() )
+}
+
+/// The unit in the nominative, `alle` takes the plural
+fn unit( position: CronPosition, plural: bool ) -> &'static str {
+    match ( position, plural ) {
+        ( CronPosition::Second, false ) => "Sekunde",
+        ( CronPosition::Second, true ) => "Sekunden",
+        ( CronPosition::Minute, false ) => "Minute",
+        ( CronPosition::Minute, true ) => "Minuten",
+        ( CronPosition::Hour, false ) => "Stunde",
+        ( CronPosition::Hour, true ) => "Stunden",
+        ( CronPosition::DayOfMonth | CronPosition::DayOfWeek, false ) => "Tag",
+        ( CronPosition::DayOfMonth | CronPosition::DayOfWeek, true ) => "Tage",
+        ( CronPosition::Month, false ) => "Monat",
+        ( CronPosition::Month, true ) => "Monate",
+        ( CronPosition::Year, false ) => "Jahr",
+        ( CronPosition::Year, true ) => "Jahre",
+    }
+}
+
+impl CronLocale for CronGerman {
+    fn value( &self, position: CronPosition, value: u32 ) -> String {
+        match position {
+            CronPosition::Hour => format!( "{value:02}" ),
+            CronPosition::DayOfMonth => format!( "{value}." ),
+            CronPosition::DayOfWeek => weekday( value ),
+            CronPosition::Month => month( value ),
+            _ => value.to_string()
+        }
+    }
+
+    fn range( &self, position: CronPosition, start: u32, end: u32 ) -> String {
+        format!( "{} bis {}", self.value( position, start ), self.value( position, end ) )
+    }
+
+    fn list( &self, items: Vec<String> ) -> String {
+        join( items, "und" )
+    }
+
+    fn at_startup( &self ) -> String {
+        "Beim Systemstart".to_owned()
+    }
+
+    fn at_times( &self, times: String ) -> String {
+        format!( "um {times} Uhr" )
+    }
+
+    fn every( &self, position: CronPosition ) -> String {
+        match position {
+            CronPosition::Second | CronPosition::Minute | CronPosition::Hour => format!( "jede {}", unit( position, false ) ),
+            CronPosition::Year => "jedes Jahr".to_owned(),
+            position => format!( "jeden {}", unit( position, false ) )
+        }
+    }
+
+    fn at( &self, position: CronPosition, values: String, count: usize ) -> String {
+        match count {
+            1 => format!( "in {} {values}", unit( position, false ) ),
+            _ => format!( "in den {} {values}", unit( position, true ) )
+        }
+    }
+
+    fn every_n( &self, position: CronPosition, step: u32 ) -> String {
+        format!( "alle {step} {}", unit( position, true ) )
+    }
+
+    fn starting( &self, position: CronPosition, every: String, start: u32 ) -> String {
+        match position {
+            CronPosition::Second | CronPosition::Minute => format!( "{every} ab {} {start}", unit( position, false ) ),
+            CronPosition::Hour => format!( "{every} ab {start:02}:00 Uhr" ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => format!( "{every} ab dem {}", self.value( position, start ) ),
+            CronPosition::Month | CronPosition::Year => format!( "{every} ab {}", self.value( position, start ) ),
+        }
+    }
+
+    fn within( &self, position: CronPosition, every: String, start: u32, end: u32 ) -> String {
+        match position {
+            CronPosition::Second | CronPosition::Minute => format!( "{every} von {} {start} bis {end}", unit( position, false ) ),
+            CronPosition::Hour => format!( "{every} {}", self.hours( start, end ) ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => format!( "{every} vom {} bis zum {}", self.value( position, start ), self.value( position, end ) ),
+            position => format!( "{every} von {}", self.range( position, start, end ) )
+        }
+    }
+
+    fn past( &self, fixed: String, every: String ) -> String {
+        format!( "{fixed}, {every}" )
+    }
+
+    fn hours( &self, start: u32, end: u32 ) -> String {
+        format!( "zwischen {start:02}:00 und {end:02}:59 Uhr" )
+    }
+
+    fn during_hours( &self, hours: String ) -> String {
+        format!( "in den Stunden {hours}" )
+    }
+
+    fn days_of_month( &self, days: String ) -> String {
+        format!( "am {days} des Monats" )
+    }
+
+    fn days_before_last_day( &self, days: String ) -> String {
+        format!( "{days} des Monats" )
+    }
+
+    fn nearest_weekday( &self, day: u32 ) -> String {
+        format!( "nächstgelegenen Werktag zum {day}." )
+    }
+
+    fn last_day( &self, offset: u32 ) -> String {
+        match offset {
+            0 => "letzten Tag".to_owned(),
+            1 => "1 Tag vor dem letzten Tag".to_owned(),
+            offset => format!( "{offset} Tage vor dem letzten Tag" )
+        }
+    }
+
+    fn last_weekday( &self ) -> String {
+        "letzten Werktag".to_owned()
+    }
+
+    fn weekdays( &self, days: String ) -> String {
+        format!( "am {days}" )
+    }
+
+    fn monday_to_friday( &self ) -> String {
+        "an jedem Werktag".to_owned()
+    }
+
+    fn nth_weekday( &self, n: u32, day: u32 ) -> String {
+        format!( "{n}. {} des Monats", weekday( day ) )
+    }
+
+    fn last_of_month( &self, day: u32 ) -> String {
+        format!( "letzten {} des Monats", weekday( day ) )
+    }
+
+    fn months( &self, months: String ) -> String {
+        format!( "im {months}" )
+    }
+
+    fn years( &self, years: String ) -> String {
+        format!( "im Jahr {years}" )
+    }
+
+    fn either( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month} oder {day_of_week}" )
+    }
+
+    fn both( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month} und {day_of_week}" )
+    }
+
+    fn clauses( &self, clauses: Vec<String> ) -> String {
+        clauses.join( ", " )
+    }
+
+    fn sentence( &self, time: String, days: Option<String>, months: Option<String>, years: Option<String> ) -> String {
+        let phrases: Vec<String> = [ Some( time ), days, months, years ].into_iter().flatten().collect();
+
+        capitalize( &phrases.join( " " ) )
+    }
+}
@@ -0,0 +1,171 @@
+use crate::position::CronPosition;
+
+use super::CronLocale;
+
+/// Describes schedules in Japanese, such as "1月から3月までの平日の09:00に"
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CronJapanese;
+
+const WEEKDAYS: [&str; 7] = [ "日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日" ];
+
+fn weekday( day: u32 ) -> String {
+    WEEKDAYS.get( day as usize ).map_or( day.to_string(), |day| day.to_string() )
+}
+
+/// The counter written after a number of units, as in 15分ごと
+fn counter( position: CronPosition ) -> &'static str {
+    match position {
+        CronPosition::Second => "秒",
+        CronPosition::Minute => "分",
+        CronPosition::Hour => "時間",
+        CronPosition::DayOfMonth | CronPosition::DayOfWeek => "日",
+        CronPosition::Month => "か月",
+        CronPosition::Year => "年",
+    }
+}
+
+impl CronLocale for CronJapanese {
+    fn value( &self, position: CronPosition, value: u32 ) -> String {
+        match position {
+            CronPosition::Second => format!( "{value}秒" ),
+            CronPosition::Minute => format!( "{value}分" ),
+            CronPosition::Hour => format!( "{value}時台" ),
+            CronPosition::DayOfMonth => format!( "{value}日" ),
+            CronPosition::DayOfWeek => weekday( value ),
+            CronPosition::Month => format!( "{value}月" ),
+            CronPosition::Year => format!( "{value}年" ),
+        }
+    }
+
+    fn range( &self, position: CronPosition, start: u32, end: u32 ) -> String {
+        format!( "{}から{}まで", self.value( position, start ), self.value( position, end ) )
+    }
+
+    fn list( &self, items: Vec<String> ) -> String {
+        items.join( "、" )
+    }
+
+    fn at_startup( &self ) -> String {
+        "起動時".to_owned()
+    }
+
+    fn at_times( &self, times: String ) -> String {
+        format!( "{times}に" )
+    }
+
+    fn every( &self, position: CronPosition ) -> String {
+        match position {
+            CronPosition::Second => "毎秒".to_owned(),
+            CronPosition::Minute => "毎分".to_owned(),
+            CronPosition::Hour => "毎時".to_owned(),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => "毎日".to_owned(),
+            CronPosition::Month => "毎月".to_owned(),
+            CronPosition::Year => "毎年".to_owned(),
+        }
+    }
+
+    fn at( &self, _position: CronPosition, values: String, _count: usize ) -> String {
+        values
+    }
+
+    fn every_n( &self, position: CronPosition, step: u32 ) -> String {
+        format!( "{step}{}ごと", counter( position ) )
+    }
+
+    fn starting( &self, position: CronPosition, every: String, start: u32 ) -> String {
+        match position {
+            CronPosition::Hour => format!( "{start:02}:00から{every}" ),
+            position => format!( "{}から{every}", self.value( position, start ) )
+        }
+    }
+
+    fn within( &self, position: CronPosition, every: String, start: u32, end: u32 ) -> String {
+        match position {
+            CronPosition::Hour => format!( "{}の{every}", self.hours( start, end ) ),
+            position => format!( "{}の{every}", self.range( position, start, end ) )
+        }
+    }
+
+    fn past( &self, fixed: String, every: String ) -> String {
+        match every.ends_with( "ごと" ) {
+            true => format!( "{every}の{fixed}" ),
+            false => format!( "{every}{fixed}" )
+        }
+    }
+
+    fn hours( &self, start: u32, end: u32 ) -> String {
+        format!( "{start:02}:00から{end:02}:59まで" )
+    }
+
+    fn during_hours( &self, hours: String ) -> String {
+        hours
+    }
+
+    fn days_of_month( &self, days: String ) -> String {
+        days
+    }
+
+    fn days_before_last_day( &self, days: String ) -> String {
+        days
+    }
+
+    fn nearest_weekday( &self, day: u32 ) -> String {
+        format!( "{day}日に最も近い平日" )
+    }
+
+    fn last_day( &self, offset: u32 ) -> String {
+        match offset {
+            0 => "末日".to_owned(),
+            offset => format!( "末日の{offset}日前" )
+        }
+    }
+
+    fn last_weekday( &self ) -> String {
+        "最終平日".to_owned()
+    }
+
+    fn weekdays( &self, days: String ) -> String {
+        days
+    }
+
+    fn monday_to_friday( &self ) -> String {
+        "平日".to_owned()
+    }
+
+    fn nth_weekday( &self, n: u32, day: u32 ) -> String {
+        format!( "第{n}{}", weekday( day ) )
+    }
+
+    fn last_of_month( &self, day: u32 ) -> String {
+        format!( "最終{}", weekday( day ) )
+    }
+
+    fn months( &self, months: String ) -> String {
+        months
+    }
+
+    fn years( &self, years: String ) -> String {
+        years
+    }
+
+    fn either( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month}または{day_of_week}" )
+    }
+
+    fn both( &self, day_of_month: String, day_of_week: String ) -> String {
+        format!( "{day_of_month}かつ{day_of_week}" )
+    }
+
+    /// The hours come before the minutes within them
+    fn clauses( &self, mut clauses: Vec<String> ) -> String {
+        clauses.reverse();
+        clauses.join( "の" )
+    }
+
+    /// Japanese goes from the largest unit to the smallest, 2030年の1月の平日の09:00に
+    fn sentence( &self, time: String, days: Option<String>, months: Option<String>, years: Option<String> ) -> String {
+        let phrases: Vec<String> = [ years, months, days, Some( time ) ].into_iter().flatten().collect();
+
+        phrases.join( "の" )
+    }
+}
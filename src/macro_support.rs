@@ -0,0 +1,61 @@
+//! Support for the `cron!` macro in `croncron-macros`, not part of the public API.
+//!
+//! Its items can change in any release, so `croncron-macros` depends on the exact version of `croncron`
+
+pub use crate::command::{CronCommand, StepBase};
+
+use crate::{CronArg, CronDayMatching, CronDialect, CronDstPolicy, CronNickname, CronPosition, CronSchedule, Tz};
+
+/// The commands of a schedule that has already been validated
+pub struct CronParts {
+    pub second: Option<CronCommand>,
+    pub minute: CronCommand,
+    pub hour: CronCommand,
+    pub day_of_month: CronCommand,
+    pub month: CronCommand,
+    pub day_of_week: CronCommand,
+    pub year: Option<CronCommand>,
+    pub nickname: Option<CronNickname>,
+    pub dialect: CronDialect,
+    pub day_matching: CronDayMatching,
+    pub time_zone: Tz,
+    pub dst_policy: CronDstPolicy
+}
+
+impl From<CronParts> for CronSchedule {
+    fn from( parts: CronParts ) -> CronSchedule {
+        CronSchedule {
+            cron_second: parts.second.map( |command| CronArg::new( CronPosition::Second, command ) ),
+            cron_minute: CronArg::new( CronPosition::Minute, parts.minute ),
+            cron_hour: CronArg::new( CronPosition::Hour, parts.hour ),
+            cron_day_of_month: CronArg::new( CronPosition::DayOfMonth, parts.day_of_month ),
+            cron_month: CronArg::new( CronPosition::Month, parts.month ),
+            cron_day_of_week: CronArg::new( CronPosition::DayOfWeek, parts.day_of_week ),
+            cron_year: parts.year.map( |command| CronArg::new( CronPosition::Year, command ) ),
+            nickname: parts.nickname,
+            dialect: parts.dialect,
+            day_matching: parts.day_matching,
+            time_zone: parts.time_zone,
+            dst_policy: parts.dst_policy,
+        }
+    }
+}
+
+impl From<CronSchedule> for CronParts {
+    fn from( schedule: CronSchedule ) -> CronParts {
+        CronParts {
+            second: schedule.cron_second.map( |arg| arg.command ),
+            minute: schedule.cron_minute.command,
+            hour: schedule.cron_hour.command,
+            day_of_month: schedule.cron_day_of_month.command,
+            month: schedule.cron_month.command,
+            day_of_week: schedule.cron_day_of_week.command,
+            year: schedule.cron_year.map( |arg| arg.command ),
+            nickname: schedule.nickname,
+            dialect: schedule.dialect,
+            day_matching: schedule.day_matching,
+            time_zone: schedule.time_zone,
+            dst_policy: schedule.dst_policy,
+        }
+    }
+}
@@ -0,0 +1,685 @@
+use std::ops::Range;
+
+use crate::command::{DayOfWeek, Month};
+use crate::{CronDayMatching, CronError, CronSchedule};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cron( phrase: &str ) -> String {
+        parse_english( phrase ).unwrap().to_string()
+    }
+
+    fn error( phrase: &str ) -> ( String, String ) {
+        let error = parse_english( phrase ).unwrap_err();
+
+        ( error.to_string(), phrase[ error.span() ].to_owned() )
+    }
+
+    #[test]
+    fn should_translate_times_and_days(){
+        assert_eq!( cron( "every weekday at 9:30am" ), "30 9 * * 1-5" );
+        assert_eq!( cron( "first Monday of every month at noon" ), "0 12 * * 1#1" );
+        assert_eq!( cron( "every day at midnight" ), "0 0 * * *" );
+        assert_eq!( cron( "at 9am and 5pm on Mondays and Fridays" ), "0 9,17 * * 1,5" );
+        assert_eq!( cron( "every Saturday, Sunday at 10:15" ), "15 10 * * 6,0" );
+        assert_eq!( cron( "on weekends at 12 am" ), "0 0 * * 0,6" );
+        assert_eq!( cron( "Monday through Thursday at 18:00" ), "0 18 * * 1-4" );
+    }
+
+    #[test]
+    fn should_translate_intervals(){
+        assert_eq!( cron( "every minute" ), "* * * * *" );
+        assert_eq!( cron( "every 15 minutes" ), "*/15 * * * *" );
+        assert_eq!( cron( "every 10 seconds" ), "*/10 * * * * *" );
+        assert_eq!( cron( "every other hour" ), "0 */2 * * *" );
+        assert_eq!( cron( "hourly" ), "0 * * * *" );
+        assert_eq!( cron( "every 15 minutes between 9am and 5pm on weekdays" ), "*/15 9-16 * * 1-5" );
+        assert_eq!( cron( "every 2 hours from 8am to 6pm" ), "0 8-17/2 * * *" );
+        assert_eq!( cron( "every hour between 9am and 10am" ), "0 9 * * *" );
+        assert_eq!( cron( "every 2 hours between 9am and 10am" ), "0 9 * * *" );
+        assert_eq!( cron( "every 15 minutes between 11pm and midnight" ), "*/15 23 * * *" );
+        assert_eq!( cron( "every 15 minutes between 10pm and 2am" ), "*/15 22-1 * * *" );
+        assert_eq!( cron( "weekly" ), "0 0 * * 0" );
+        assert_eq!( cron( "every 3 months" ), "0 0 1 */3 *" );
+        assert_eq!( cron( "yearly" ), "0 0 1 1 *" );
+    }
+
+    #[test]
+    fn should_translate_days_of_month_and_months(){
+        assert_eq!( cron( "on the 1st and 15th at 6am" ), "0 6 1,15 * *" );
+        assert_eq!( cron( "on the last day of the month at 23:30" ), "30 23 L * *" );
+        assert_eq!( cron( "the last weekday of every month" ), "0 0 LW * *" );
+        assert_eq!( cron( "the last Friday of the month at 5pm" ), "0 17 * * 5L" );
+        assert_eq!( cron( "every day in January and July at 8" ), "0 8 * 1,7 *" );
+        assert_eq!( cron( "on the 1st of every month from April to June" ), "0 0 1 4-6 *" );
+        assert_eq!( cron( "the 2nd Tuesday of March" ), "0 0 * 3 2#2" );
+    }
+
+    #[test]
+    fn should_match_both_day_fields(){
+        let schedule = parse_english( "every Friday on the 13th" ).unwrap();
+
+        assert_eq!( schedule.to_string(), "0 0 13 * 5" );
+        assert_eq!( schedule.day_matching(), CronDayMatching::Both );
+    }
+
+    #[test]
+    fn should_point_at_what_it_could_not_understand(){
+        assert_eq!( error( "every weekday at teatime" ), ( "Could not understand \"teatime\"".to_owned(), "teatime".to_owned() ) );
+        assert_eq!( error( "every fortnight" ), ( "Could not understand \"fortnight\"".to_owned(), "fortnight".to_owned() ) );
+        assert_eq!( error( "at 25:00" ), ( "Could not understand \"25:00\"".to_owned(), "25:00".to_owned() ) );
+        assert_eq!( error( "on the 32nd" ), ( "Could not understand \"32nd\"".to_owned(), "32nd".to_owned() ) );
+        assert_eq!( error( "every" ), ( "Could not understand \"every\"".to_owned(), "every".to_owned() ) );
+        assert_eq!( error( "" ), ( "Could not understand \"\"".to_owned(), "".to_owned() ) );
+    }
+
+    #[test]
+    fn should_point_at_what_has_no_cron_equivalent(){
+        assert_eq!( error( "every 7 minutes" ), ( "\"every 7 minutes\" has no cron equivalent".to_owned(), "every 7 minutes".to_owned() ) );
+        assert_eq!( error( "every weekday at 9:30 and 17:00" ), ( "\"9:30 and 17:00\" has no cron equivalent".to_owned(), "9:30 and 17:00".to_owned() ) );
+        assert_eq!( error( "every 2 days" ), ( "\"every 2 days\" has no cron equivalent".to_owned(), "every 2 days".to_owned() ) );
+        assert_eq!( error( "between 9:30 and 17:00" ), ( "\"between 9:30 and 17:00\" has no cron equivalent".to_owned(), "between 9:30 and 17:00".to_owned() ) );
+        assert!( matches!( parse_english( "on the 31st of February" ), Err( CronError::Unsatisfiable { .. } ) ) );
+    }
+}
+
+/// The unit of a repeating clause such as "every 15 minutes"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year
+}
+
+impl Unit {
+    /// Accepts the singular or the plural
+    fn from_word( word: &str ) -> Option<Unit> {
+        match word.strip_suffix( 's' ).unwrap_or( word ) {
+            "second" => Some( Unit::Second ),
+            "minute" => Some( Unit::Minute ),
+            "hour" => Some( Unit::Hour ),
+            "day" => Some( Unit::Day ),
+            "week" => Some( Unit::Week ),
+            "month" => Some( Unit::Month ),
+            "year" => Some( Unit::Year ),
+            _ => None
+        }
+    }
+
+    /// Whether the unit repeats within a day, as opposed to picking days
+    fn is_time( &self ) -> bool {
+        matches!( self, Unit::Second | Unit::Minute | Unit::Hour )
+    }
+}
+
+/// Parses a phrase such as "every weekday at 9:30am" or "first Monday of every month at noon".
+///
+/// The phrase is a series of clauses in any order:
+/// - `every [n|other] <unit>`, `hourly`, `daily`, `weekly`, `monthly`, `yearly`
+/// - `at <time> [and <time>]`, times written as `9`, `9am`, `9:30 pm`, `17:00`, `noon` or `midnight`
+/// - `between|from <time> and|to <time>`, limiting the hours of a repeating clause
+/// - `[on|every] <days>`, weekdays, weekends, Mondays, Monday through Friday, the 1st and 15th,
+///   the first Monday, the last Friday, the last day or the last weekday
+/// - `in|of <months>`, January, January and July or April to June
+pub fn parse_english( phrase: &str ) -> Result<CronSchedule, CronError> {
+    let mut parser = Phrase { input: phrase, words: split_words( phrase ), index: 0, found: Found::default() };
+
+    if parser.words.is_empty() {
+        return Err( CronError::UnknownPhrase { token: String::new(), span: 0..phrase.len() } );
+    }
+
+    while parser.index < parser.words.len() {
+        parser.clause()?;
+    }
+
+    parser.found.schedule( phrase )
+}
+
+/// The words of the phrase in lower case, each paired with where it is in the phrase
+fn split_words( phrase: &str ) -> Vec<( Range<usize>, String )> {
+    let mut words = vec![];
+    let mut start = None;
+
+    for ( i, c ) in phrase.char_indices().chain( [ ( phrase.len(), ' ' ) ] ) {
+        match ( c.is_whitespace() || c == ',', start ) {
+            ( true, Some( word_start ) ) => {
+                words.push( ( word_start..i, phrase[ word_start..i ].to_lowercase() ) );
+                start = None;
+            },
+            ( false, None ) => start = Some( i ),
+            _ => {}
+        }
+    }
+
+    words
+}
+
+/// What the clauses of a phrase asked for, kept apart until they are combined into fields
+#[derive(Debug, Default)]
+struct Found {
+    interval: Option<( Unit, u32, Range<usize> )>,
+    times: Vec<( u32, u32 )>,
+    times_span: Range<usize>,
+    hours: Option<( u32, u32, Range<usize> )>,
+    day_of_month: Vec<String>,
+    day_of_week: Vec<String>,
+    month: Vec<String>
+}
+
+impl Found {
+    fn schedule( self, phrase: &str ) -> Result<CronSchedule, CronError> {
+        let untranslatable = |span: &Range<usize>| CronError::UntranslatablePhrase { token: phrase[ span.clone() ].to_owned(), span: span.clone() };
+
+        // The end hour is where the phrase stops, so the last hour that runs is the one before
+        let ( hours, one_hour ) = match &self.hours {
+            Some( ( start, end, _ ) ) if ( end + 23 ) % 24 == *start => ( start.to_string(), true ),
+            Some( ( start, end, _ ) ) => ( format!( "{start}-{}", ( end + 23 ) % 24 ), false ),
+            None => ( "*".to_owned(), false )
+        };
+
+        let ( mut second, mut minute, mut hour ) = ( None, "0".to_owned(), "0".to_owned() );
+
+        match ( &self.interval, self.times.is_empty() ) {
+            ( Some( ( unit, _, span ) ), false ) if unit.is_time() => return Err( untranslatable( span ) ),
+            ( _, false ) => {
+                if let Some( ( _, _, span ) ) = &self.hours {
+                    return Err( untranslatable( span ) );
+                }
+
+                let minutes = self.times[ 0 ].1;
+
+                if self.times.iter().any( |( _, m )| *m != minutes ) {
+                    return Err( untranslatable( &self.times_span ) );
+                }
+
+                minute = minutes.to_string();
+                hour = dedup( self.times.iter().map( |( h, _ )| h.to_string() ).collect() ).join( "," );
+            },
+            ( Some( ( Unit::Second, n, span ) ), true ) => {
+                second = Some( step( *n, 60 ).ok_or_else( || untranslatable( span ) )? );
+                minute = "*".to_owned();
+                hour = hours;
+            },
+            ( Some( ( Unit::Minute, n, span ) ), true ) => {
+                minute = step( *n, 60 ).ok_or_else( || untranslatable( span ) )?;
+                hour = hours;
+            },
+            ( Some( ( Unit::Hour, n, span ) ), true ) => {
+                hour = match ( &self.hours, n ) {
+                    ( Some( _ ), _ ) if one_hour => hours,
+                    ( Some( _ ), 1 ) => hours,
+                    ( Some( _ ), n ) => format!( "{hours}/{n}" ),
+                    ( None, n ) => step( *n, 24 ).ok_or_else( || untranslatable( span ) )?
+                };
+            },
+            ( _, true ) => {
+                if self.hours.is_some() {
+                    minute = "*".to_owned();
+                    hour = hours;
+                }
+            }
+        }
+
+        let mut day_of_month = self.day_of_month;
+        let mut day_of_week = self.day_of_week;
+        let mut month = self.month;
+        let no_days = day_of_month.is_empty() && day_of_week.is_empty();
+
+        match &self.interval {
+            Some( ( Unit::Day | Unit::Week | Unit::Year, n, span ) ) if *n > 1 => return Err( untranslatable( span ) ),
+            Some( ( Unit::Week, _, _ ) ) if no_days => day_of_week.push( "0".to_owned() ),
+            Some( ( Unit::Month, n, span ) ) => {
+                if *n > 1 {
+                    month.push( step( *n, 12 ).ok_or_else( || untranslatable( span ) )? );
+                }
+
+                if no_days {
+                    day_of_month.push( "1".to_owned() );
+                }
+            },
+            Some( ( Unit::Year, _, _ ) ) => {
+                if month.is_empty() {
+                    month.push( "1".to_owned() );
+                }
+
+                if no_days {
+                    day_of_month.push( "1".to_owned() );
+                }
+            },
+            _ => {}
+        }
+
+        let both_days = !day_of_month.is_empty() && !day_of_week.is_empty();
+        let field = |values: Vec<String>| if values.is_empty() { "*".to_owned() } else { dedup( values ).join( "," ) };
+        let fields = [ minute, hour, field( day_of_month ), field( month ), field( day_of_week ) ];
+
+        let schedule = match second {
+            Some( second ) => CronSchedule::new_with_seconds( &second, &fields[ 0 ], &fields[ 1 ], &fields[ 2 ], &fields[ 3 ], &fields[ 4 ] ),
+            None => CronSchedule::new( &fields[ 0 ], &fields[ 1 ], &fields[ 2 ], &fields[ 3 ], &fields[ 4 ] )
+        };
+
+        // The fields are built from checked values, so what can still fail is a day that never comes or the window of hours
+        let schedule = schedule.map_err( |error| match ( error, &self.hours ) {
+            ( CronError::Unsatisfiable { .. }, _ ) => CronError::Unsatisfiable { token: phrase.to_owned(), span: 0..phrase.len() },
+            ( _, Some( ( _, _, span ) ) ) => untranslatable( span ),
+            ( _, None ) => untranslatable( &( 0..phrase.len() ) )
+        })?;
+
+        match both_days {
+            true => Ok( schedule.with_day_matching( CronDayMatching::Both ) ),
+            false => Ok( schedule )
+        }
+    }
+}
+
+/// `*` or `*/n`, `None` when the steps would not be evenly spaced across the wrap at `size`
+fn step( n: u32, size: u32 ) -> Option<String> {
+    match n {
+        1 => Some( "*".to_owned() ),
+        n if size.is_multiple_of( n ) && n < size => Some( format!( "*/{n}" ) ),
+        _ => None
+    }
+}
+
+/// Removes repeated values, keeping the first of each
+fn dedup( values: Vec<String> ) -> Vec<String> {
+    let mut kept: Vec<String> = vec![];
+
+    for value in values {
+        if !kept.contains( &value ) {
+            kept.push( value );
+        }
+    }
+
+    kept
+}
+
+/// A weekday name, abbreviation or plural, such as "Monday", "mon" or "Mondays"
+fn weekday( word: &str ) -> Option<u32> {
+    DayOfWeek::from_name( word )
+        .or_else( || DayOfWeek::from_name( word.strip_suffix( 's' )? ) )
+        .map( |day| day.index() )
+}
+
+fn month( word: &str ) -> Option<u32> {
+    Month::from_name( word ).map( |month| month.index() )
+}
+
+/// "first" to "fifth", or a number with its suffix such as "1st" or "15th"
+fn ordinal( word: &str ) -> Option<u32> {
+    match word {
+        "first" => Some( 1 ),
+        "second" => Some( 2 ),
+        "third" => Some( 3 ),
+        "fourth" => Some( 4 ),
+        "fifth" => Some( 5 ),
+        word => {
+            let digits = word.strip_suffix( "st" )
+                .or_else( || word.strip_suffix( "nd" ) )
+                .or_else( || word.strip_suffix( "rd" ) )
+                .or_else( || word.strip_suffix( "th" ) )?;
+
+            number( digits )
+        }
+    }
+}
+
+fn number( word: &str ) -> Option<u32> {
+    match !word.is_empty() && word.bytes().all( |b| b.is_ascii_digit() ) {
+        true => word.parse().ok(),
+        false => None
+    }
+}
+
+/// A clock time as an hour and minute, `9`, `9am`, `9:30pm`, `17:00`, `noon` or `midnight`,
+/// `meridiem` is a separate "am" or "pm" following the word
+fn time( word: &str, meridiem: Option<&str> ) -> Option<( u32, u32 )> {
+    match word {
+        "noon" => return Some( ( 12, 0 ) ),
+        "midnight" => return Some( ( 0, 0 ) ),
+        _ => {}
+    }
+
+    let ( clock, meridiem ) = match ( word.strip_suffix( "am" ), word.strip_suffix( "pm" ) ) {
+        ( Some( clock ), _ ) => ( clock, Some( "am" ) ),
+        ( _, Some( clock ) ) => ( clock, Some( "pm" ) ),
+        _ => ( word, meridiem )
+    };
+
+    let ( hour, minute ) = match clock.split_once( ':' ) {
+        Some( ( hour, minute ) ) if minute.len() == 2 => ( number( hour )?, number( minute )? ),
+        Some( _ ) => return None,
+        None => ( number( clock )?, 0 )
+    };
+
+    let hour = match meridiem {
+        Some( _ ) if !( 1..=12 ).contains( &hour ) => return None,
+        Some( "am" ) => hour % 12,
+        Some( _ ) => hour % 12 + 12,
+        None => hour
+    };
+
+    ( hour <= 23 && minute <= 59 ).then_some( ( hour, minute ) )
+}
+
+/// Reads the clauses of a phrase one word at a time
+struct Phrase<'a> {
+    input: &'a str,
+    words: Vec<( Range<usize>, String )>,
+    index: usize,
+    found: Found
+}
+
+impl Phrase<'_> {
+    fn peek( &self ) -> Option<&str> {
+        self.words.get( self.index ).map( |( _, word )| word.as_str() )
+    }
+
+    /// The word after the next one
+    fn peek_second( &self ) -> Option<&str> {
+        self.words.get( self.index + 1 ).map( |( _, word )| word.as_str() )
+    }
+
+    /// Moves past the next word when it is one of the expected ones
+    fn eat( &mut self, expected: &[&str] ) -> bool {
+        match self.peek() {
+            Some( word ) if expected.contains( &word ) => {
+                self.index += 1;
+                true
+            },
+            _ => false
+        }
+    }
+
+    fn next( &mut self ) -> Result<String, CronError> {
+        match self.words.get( self.index ) {
+            Some( ( _, word ) ) => {
+                self.index += 1;
+                Ok( word.clone() )
+            },
+            None => Err( self.unknown( self.index ) )
+        }
+    }
+
+    /// The word at `index` is not understood, past the end this is the last word
+    fn unknown( &self, index: usize ) -> CronError {
+        let ( span, _ ) = &self.words[ index.min( self.words.len() - 1 ) ];
+
+        CronError::UnknownPhrase { token: self.input[ span.clone() ].to_owned(), span: span.clone() }
+    }
+
+    /// From the start of the word at `start` to the end of the last word read
+    fn span_from( &self, start: usize ) -> Range<usize> {
+        self.words[ start ].0.start..self.words[ self.index - 1 ].0.end
+    }
+
+    fn clause( &mut self ) -> Result<(), CronError> {
+        let start = self.index;
+
+        match self.next()?.as_str() {
+            "every" | "each" => self.every( start ),
+            "hourly" => self.interval( Unit::Hour, 1, start ),
+            "daily" => self.interval( Unit::Day, 1, start ),
+            "weekly" => self.interval( Unit::Week, 1, start ),
+            "monthly" => self.interval( Unit::Month, 1, start ),
+            "yearly" | "annually" => self.interval( Unit::Year, 1, start ),
+            "at" => self.times(),
+            "noon" | "midnight" => {
+                self.index = start;
+                self.times()
+            },
+            "between" | "from" if self.peek().and_then( month ).is_none() => self.hours( start ),
+            "on" => self.days(),
+            "in" | "of" | "from" => self.months(),
+            "and" => Ok( () ),
+            word if day_word( word ) => {
+                self.index = start;
+                self.days()
+            },
+            _ => Err( self.unknown( start ) )
+        }
+    }
+
+    /// `every` has been read, a unit, a number of units, weekdays or months follow
+    fn every( &mut self, start: usize ) -> Result<(), CronError> {
+        let word = self.next()?;
+
+        if let Some( unit ) = Unit::from_word( &word ) {
+            return self.interval( unit, 1, start );
+        }
+
+        let n = match ( word.as_str(), number( &word ) ) {
+            ( "other", _ ) => 2,
+            ( _, Some( n ) ) if n > 0 => n,
+            _ if weekday( &word ).is_some() || [ "weekday", "weekend" ].contains( &word.as_str() ) => {
+                self.index -= 1;
+                return self.days();
+            },
+            _ if month( &word ).is_some() => {
+                self.index -= 1;
+                return self.months();
+            },
+            _ => return Err( self.unknown( self.index - 1 ) )
+        };
+
+        let word = self.next()?;
+
+        match Unit::from_word( &word ) {
+            Some( unit ) => self.interval( unit, n, start ),
+            None => Err( self.unknown( self.index - 1 ) )
+        }
+    }
+
+    fn interval( &mut self, unit: Unit, n: u32, start: usize ) -> Result<(), CronError> {
+        let span = self.span_from( start );
+        let found = self.found.interval.as_ref().map( |( found, n, _ )| ( *found, *n ) );
+
+        match ( found, unit, n ) {
+            // "every day" adds nothing to a repeating time of day
+            ( Some( ( found, _ ) ), Unit::Day, 1 ) if found.is_time() => {},
+            ( None, _, _ ) | ( Some( ( Unit::Day, 1 ) ), _, _ ) => self.found.interval = Some( ( unit, n, span ) ),
+            _ => return Err( CronError::UntranslatablePhrase { token: self.input[ span.clone() ].to_owned(), span } )
+        }
+
+        Ok( () )
+    }
+
+    /// Clock times joined by `and`
+    fn times( &mut self ) -> Result<(), CronError> {
+        let start = self.index;
+
+        loop {
+            let time = self.time()?;
+
+            self.found.times.push( time );
+
+            let more = self.peek() == Some( "and" ) && self.peek_second().is_some_and( time_word );
+
+            if !more {
+                break;
+            }
+
+            self.index += 1;
+        }
+
+        self.found.times_span = self.span_from( start );
+
+        Ok( () )
+    }
+
+    /// One clock time, with an optional separate "am", "pm" or "o'clock"
+    fn time( &mut self ) -> Result<( u32, u32 ), CronError> {
+        let start = self.index;
+        let word = self.next()?;
+        let meridiem = match self.peek() {
+            Some( meridiem @ ( "am" | "pm" ) ) => Some( meridiem.to_owned() ),
+            _ => None
+        };
+
+        let time = time( &word, meridiem.as_deref() ).ok_or_else( || self.unknown( start ) )?;
+
+        if meridiem.is_some() || self.peek() == Some( "o'clock" ) {
+            self.index += 1;
+        }
+
+        Ok( time )
+    }
+
+    /// `between` or `from` has been read, two whole hours limit a repeating clause
+    fn hours( &mut self, start: usize ) -> Result<(), CronError> {
+        let ( from, from_minute ) = self.time()?;
+
+        if !self.eat( &[ "and", "to", "until", "till" ] ) {
+            return Err( self.unknown( self.index ) );
+        }
+
+        let ( to, to_minute ) = self.time()?;
+        let span = self.span_from( start );
+
+        if from_minute != 0 || to_minute != 0 || from == to {
+            return Err( CronError::UntranslatablePhrase { token: self.input[ span.clone() ].to_owned(), span } );
+        }
+
+        self.found.hours = Some( ( from, to, span ) );
+
+        Ok( () )
+    }
+
+    /// Days joined by `and`, each a weekday or range of them, a day of the month or a weekday within the month
+    fn days( &mut self ) -> Result<(), CronError> {
+        self.eat( &[ "every", "each" ] );
+
+        loop {
+            self.eat( &[ "the" ] );
+            self.day()?;
+
+            let more = self.peek() == Some( "and" ) && self.peek_second().is_some_and( day_word );
+
+            if !more && !self.peek().is_some_and( day_word ) {
+                return Ok( () );
+            }
+
+            self.eat( &[ "and" ] );
+        }
+    }
+
+    fn day( &mut self ) -> Result<(), CronError> {
+        let start = self.index;
+        let word = self.next()?;
+
+        if let Some( day ) = weekday( &word ) {
+            let day = match self.eat( &[ "through", "to", "until", "till" ] ) {
+                true => {
+                    let end_index = self.index;
+                    let end = weekday( &self.next()? ).ok_or_else( || self.unknown( end_index ) )?;
+
+                    format!( "{day}-{end}" )
+                },
+                false => day.to_string()
+            };
+
+            self.found.day_of_week.push( day );
+            return Ok( () );
+        }
+
+        match word.as_str() {
+            "weekday" | "weekdays" => self.found.day_of_week.push( "1-5".to_owned() ),
+            "weekend" | "weekends" => self.found.day_of_week.push( "0,6".to_owned() ),
+            "last" => {
+                let next_index = self.index;
+
+                match self.next()?.as_str() {
+                    "day" => self.found.day_of_month.push( "L".to_owned() ),
+                    "weekday" => self.found.day_of_month.push( "LW".to_owned() ),
+                    next => {
+                        let day = weekday( next ).ok_or_else( || self.unknown( next_index ) )?;
+
+                        self.found.day_of_week.push( format!( "{day}L" ) );
+                    }
+                }
+            },
+            word => {
+                let n = ordinal( word ).ok_or_else( || self.unknown( start ) )?;
+
+                match self.peek().and_then( weekday ) {
+                    Some( day ) if n <= 5 => {
+                        self.index += 1;
+                        self.found.day_of_week.push( format!( "{day}#{n}" ) );
+                    },
+                    _ if ( 1..=31 ).contains( &n ) => {
+                        self.eat( &[ "day" ] );
+                        self.found.day_of_month.push( n.to_string() );
+                    },
+                    _ => return Err( self.unknown( start ) )
+                }
+            }
+        }
+
+        Ok( () )
+    }
+
+    /// `in`, `of` or `from` has been read, months joined by `and` or a range of them,
+    /// or the filler "the month" and "every month"
+    fn months( &mut self ) -> Result<(), CronError> {
+        let start = self.index - 1;
+
+        if self.eat( &[ "every", "each" ] ) {
+            let word = self.next()?;
+
+            return match ( Unit::from_word( &word ), month( &word ) ) {
+                ( Some( unit ), _ ) => self.interval( unit, 1, start ),
+                ( _, Some( _ ) ) => {
+                    self.index -= 1;
+                    self.months()
+                },
+                _ => Err( self.unknown( self.index - 1 ) )
+            };
+        }
+
+        if self.peek() == Some( "the" ) && self.peek_second() == Some( "month" ) {
+            self.index += 2;
+            return Ok( () );
+        }
+
+        loop {
+            let word_index = self.index;
+            let start = month( &self.next()? ).ok_or_else( || self.unknown( word_index ) )?;
+
+            let value = match self.eat( &[ "through", "to", "until", "till" ] ) {
+                true => {
+                    let end_index = self.index;
+                    let end = month( &self.next()? ).ok_or_else( || self.unknown( end_index ) )?;
+
+                    format!( "{start}-{end}" )
+                },
+                false => start.to_string()
+            };
+
+            self.found.month.push( value );
+
+            let more = self.peek() == Some( "and" ) && self.peek_second().and_then( month ).is_some();
+
+            if !more && self.peek().and_then( month ).is_none() {
+                return Ok( () );
+            }
+
+            self.eat( &[ "and" ] );
+        }
+    }
+}
+
+/// Whether a word can start a clock time
+fn time_word( word: &str ) -> bool {
+    time( word, Some( "am" ) ).is_some() || time( word, None ).is_some()
+}
+
+/// Whether a word can start a day
+fn day_word( word: &str ) -> bool {
+    weekday( word ).is_some() || ordinal( word ).is_some() || [ "the", "last", "weekday", "weekdays", "weekend", "weekends" ].contains( &word )
+}
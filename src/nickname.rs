@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn should_parse_nicknames_in_any_case(){
+        assert!( matches!( CronNickname::from_name( "@daily" ), Some( CronNickname::Daily ) ) );
+        assert!( matches!( CronNickname::from_name( "@HOURLY" ), Some( CronNickname::Hourly ) ) );
+        assert!( matches!( CronNickname::from_name( "@reboot" ), Some( CronNickname::Reboot ) ) );
+        assert!( CronNickname::from_name( "daily" ).is_none() );
+        assert!( CronNickname::from_name( "@fortnightly" ).is_none() );
+    }
+
+    #[test]
+    fn should_round_trip_nickname(){
+        assert_eq!( CronNickname::from_name( "@annually" ).unwrap().to_string(), "@annually" );
+        assert_eq!( CronNickname::from_name( "@midnight" ).unwrap().to_string(), "@midnight" );
+    }
+}
+
+/// The predefined Vixie cron schedules
+#[derive(Debug)]
+pub enum CronNickname {
+    Yearly,
+    Annually,
+    Monthly,
+    Weekly,
+    Daily,
+    Midnight,
+    Hourly,
+    Reboot
+}
+
+impl CronNickname {
+    pub fn from_name( val: &str ) -> Option<CronNickname> {
+        match val.to_ascii_lowercase().as_str() {
+            "@yearly" => Some(CronNickname::Yearly),
+            "@annually" => Some(CronNickname::Annually),
+            "@monthly" => Some(CronNickname::Monthly),
+            "@weekly" => Some(CronNickname::Weekly),
+            "@daily" => Some(CronNickname::Daily),
+            "@midnight" => Some(CronNickname::Midnight),
+            "@hourly" => Some(CronNickname::Hourly),
+            "@reboot" => Some(CronNickname::Reboot),
+            _ => None
+        }
+    }
+
+    /// The five field expression the nickname stands for, `@reboot` has none
+    pub fn expression( &self ) -> Option<&'static str> {
+        match self {
+            CronNickname::Yearly | CronNickname::Annually => Some( "0 0 1 1 *" ),
+            CronNickname::Monthly => Some( "0 0 1 * *" ),
+            CronNickname::Weekly => Some( "0 0 * * 0" ),
+            CronNickname::Daily | CronNickname::Midnight => Some( "0 0 * * *" ),
+            CronNickname::Hourly => Some( "0 * * * *" ),
+            CronNickname::Reboot => None,
+        }
+    }
+}
+
+impl Display for CronNickname {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            CronNickname::Yearly => "@yearly",
+            CronNickname::Annually => "@annually",
+            CronNickname::Monthly => "@monthly",
+            CronNickname::Weekly => "@weekly",
+            CronNickname::Daily => "@daily",
+            CronNickname::Midnight => "@midnight",
+            CronNickname::Hourly => "@hourly",
+            CronNickname::Reboot => "@reboot",
+        };
+
+        write!( f, "{display}" )
+    }
+}
@@ -0,0 +1,124 @@
+use std::iter::FusedIterator;
+
+use chrono::{DateTime, TimeZone};
+
+use crate::CronSchedule;
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::CronSchedule;
+
+    #[test]
+    fn should_iterate_forwards_from_start(){
+        let c = CronSchedule::from_str( "0 9 * * MON-FRI" ).unwrap();
+        let occurrences: Vec<_> = c.occurrences( Utc.with_ymd_and_hms(2022, 12, 16, 9, 0, 0).unwrap() ).take( 3 ).collect();
+
+        assert_eq!( occurrences, vec![
+            Utc.with_ymd_and_hms(2022, 12, 19, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 20, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 21, 9, 0, 0).unwrap(),
+        ] );
+    }
+
+    #[test]
+    fn should_iterate_backwards_from_end(){
+        let c = CronSchedule::from_str( "0 0 L * *" ).unwrap();
+        let occurrences: Vec<_> = c.occurrences_rev( Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap() ).take( 3 ).collect();
+
+        assert_eq!( occurrences, vec![
+            Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2022, 12, 31, 0, 0, 0).unwrap(),
+        ] );
+    }
+
+    #[test]
+    fn should_include_start_and_exclude_end_between(){
+        let c = CronSchedule::from_str( "0 * * * *" ).unwrap();
+        let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let occurrences: Vec<_> = c.occurrences_between( start, Utc.with_ymd_and_hms(2023, 1, 1, 3, 0, 0).unwrap() ).collect();
+
+        assert_eq!( occurrences, vec![
+            start,
+            Utc.with_ymd_and_hms(2023, 1, 1, 1, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 1, 2, 0, 0).unwrap(),
+        ] );
+
+        assert_eq!( c.occurrences_between( start, start ).count(), 0 );
+    }
+
+    #[test]
+    fn should_end_when_schedule_stops_occurring(){
+        let c = CronSchedule::from_str( "0 0 0 1 1 * 2030-2032" ).unwrap();
+
+        assert_eq!( c.occurrences( Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() ).count(), 3 );
+        assert_eq!( c.occurrences_rev( Utc.with_ymd_and_hms(2099, 1, 1, 0, 0, 0).unwrap() ).count(), 3 );
+    }
+
+    #[test]
+    fn should_enumerate_a_year_of_minutes(){
+        let c = CronSchedule::from_str( "* * * * *" ).unwrap();
+        let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!( c.occurrences_between( start, end ).count(), 365 * 24 * 60 );
+    }
+}
+
+/// The occurrences of a schedule in order, created by `CronSchedule::occurrences` and `CronSchedule::occurrences_between`
+#[derive(Debug, Clone)]
+pub struct CronOccurrences<'a, Z: TimeZone> {
+    schedule: &'a CronSchedule,
+    after: Option<DateTime<Z>>,
+    end: Option<DateTime<Z>>
+}
+
+impl<'a, Z: TimeZone> CronOccurrences<'a, Z> {
+    pub(crate) fn new( schedule: &'a CronSchedule, after: DateTime<Z>, end: Option<DateTime<Z>> ) -> CronOccurrences<'a, Z> {
+        CronOccurrences { schedule, after: Some( after ), end }
+    }
+}
+
+impl<Z: TimeZone> Iterator for CronOccurrences<'_, Z> {
+    type Item = DateTime<Z>;
+
+    fn next( &mut self ) -> Option<DateTime<Z>> {
+        let next = self.schedule.get_next_occurrence( self.after.take()? )
+            .filter( |next| self.end.as_ref().is_none_or( |end| next < end ) )?;
+
+        self.after = Some( next.clone() );
+        Some( next )
+    }
+}
+
+impl<Z: TimeZone> FusedIterator for CronOccurrences<'_, Z> {}
+
+/// The occurrences of a schedule from latest to earliest, created by `CronSchedule::occurrences_rev`
+#[derive(Debug, Clone)]
+pub struct CronOccurrencesRev<'a, Z: TimeZone> {
+    schedule: &'a CronSchedule,
+    before: Option<DateTime<Z>>
+}
+
+impl<'a, Z: TimeZone> CronOccurrencesRev<'a, Z> {
+    pub(crate) fn new( schedule: &'a CronSchedule, before: DateTime<Z> ) -> CronOccurrencesRev<'a, Z> {
+        CronOccurrencesRev { schedule, before: Some( before ) }
+    }
+}
+
+impl<Z: TimeZone> Iterator for CronOccurrencesRev<'_, Z> {
+    type Item = DateTime<Z>;
+
+    fn next( &mut self ) -> Option<DateTime<Z>> {
+        let previous = self.schedule.get_previous_occurrence( self.before.take()? )?;
+
+        self.before = Some( previous.clone() );
+        Some( previous )
+    }
+}
+
+impl<Z: TimeZone> FusedIterator for CronOccurrencesRev<'_, Z> {}
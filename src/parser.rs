@@ -0,0 +1,220 @@
+use crate::command::{CronCommand, StepBase};
+use crate::errors::FieldError;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens( field: &str ) -> Vec<Token<'_>> {
+        Tokenizer::new( field ).collect::<Result<_, _>>().unwrap()
+    }
+
+    fn parse( field: &str ) -> Result<CronCommand, FieldError> {
+        parse_field( field, &|name| ( name == "MON" ).then_some( 1 ) )
+    }
+
+    #[test]
+    fn should_split_field_into_tokens(){
+        assert_eq!( tokens( "10-50/5" ), vec![ Token::Number( 10 ), Token::Dash, Token::Number( 50 ), Token::Slash, Token::Number( 5 ) ] );
+        assert_eq!( tokens( "5L,MON#2" ), vec![ Token::Number( 5 ), Token::Word( "L" ), Token::Comma, Token::Word( "MON" ), Token::Hash, Token::Number( 2 ) ] );
+        assert_eq!( tokens( "*/?" ), vec![ Token::Asterisk, Token::Slash, Token::Question ] );
+    }
+
+    #[test]
+    fn should_fail_to_tokenize_unknown_characters(){
+        assert!( Tokenizer::new( "1.5" ).collect::<Result<Vec<_>, _>>().is_err() );
+        assert!( Tokenizer::new( "5é" ).collect::<Result<Vec<_>, _>>().is_err() );
+        assert!( Tokenizer::new( "99999999999" ).collect::<Result<Vec<_>, _>>().is_err() );
+    }
+
+    #[test]
+    fn should_parse_each_item(){
+        assert!( matches!( parse( "MON-5/2" ), Ok( CronCommand::Step( StepBase::Range( 1, 5 ), 2 ) ) ) );
+        assert!( matches!( parse( "MON/2" ), Ok( CronCommand::Step( StepBase::Start( 1 ), 2 ) ) ) );
+        assert!( matches!( parse( "MON#2" ), Ok( CronCommand::Nth( 1, 2 ) ) ) );
+        assert!( matches!( parse( "15W" ), Ok( CronCommand::W( 15 ) ) ) );
+        assert!( matches!( parse( "L-2" ), Ok( CronCommand::LastDay( 2 ) ) ) );
+        assert!( matches!( parse( "?" ), Ok( CronCommand::NoSpecific ) ) );
+    }
+
+    #[test]
+    fn should_reject_trailing_or_misplaced_tokens(){
+        for field in [ "abc/5x", "5/5x", "x5W", "5WW", "1-", "-1", "1-2-3", "*/", "*5", "L5", "5#", "TUE", "mon", "5MON", "1,", ",1", "" ] {
+            assert!( parse( field ).is_err(), "{field} should be rejected" );
+        }
+    }
+}
+
+/// A token of a single cron field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    Number(u32),
+    Word(&'a str),
+    Asterisk,
+    Question,
+    Comma,
+    Dash,
+    Slash,
+    Hash
+}
+
+/// Splits a field into tokens in a single pass without allocating, runs of digits and of letters are one token each
+#[derive(Debug, Clone, Copy)]
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    offset: usize
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new( input: &'a str ) -> Tokenizer<'a> {
+        Tokenizer { input, offset: 0 }
+    }
+
+    /// The next token without moving past it
+    fn peek( &self ) -> Option<Result<Token<'a>, FieldError>> {
+        self.clone().next()
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Token<'a>, FieldError>;
+
+    fn next( &mut self ) -> Option<Result<Token<'a>, FieldError>> {
+        let rest = &self.input[ self.offset.. ];
+        let first = *rest.as_bytes().first()?;
+
+        let run = |accept: fn( &u8 ) -> bool| rest.bytes().take_while( accept ).count();
+
+        let ( length, token ) = match first {
+            b'0'..=b'9' => {
+                let length = run( u8::is_ascii_digit );
+
+                match rest[ ..length ].parse() {
+                    Ok( n ) => ( length, Ok( Token::Number( n ) ) ),
+                    Err( _ ) => ( length, Err( FieldError::InvalidArgument ) )
+                }
+            },
+            b'A'..=b'Z' | b'a'..=b'z' => {
+                let length = run( u8::is_ascii_alphabetic );
+
+                ( length, Ok( Token::Word( &rest[ ..length ] ) ) )
+            },
+            b'*' => ( 1, Ok( Token::Asterisk ) ),
+            b'?' => ( 1, Ok( Token::Question ) ),
+            b',' => ( 1, Ok( Token::Comma ) ),
+            b'-' => ( 1, Ok( Token::Dash ) ),
+            b'/' => ( 1, Ok( Token::Slash ) ),
+            b'#' => ( 1, Ok( Token::Hash ) ),
+            // Stop at anything else, it may not even be a whole character
+            _ => ( rest.len(), Err( FieldError::InvalidArgument ) )
+        };
+
+        self.offset += length;
+        Some( token )
+    }
+}
+
+/// Parses a whole field, `names` resolves the month or weekday names the position accepts
+pub fn parse_field( input: &str, names: &dyn Fn( &str ) -> Option<u32> ) -> Result<CronCommand, FieldError> {
+    let mut parser = Parser { tokens: Tokenizer::new( input ), names };
+    let mut items = vec![ parser.item()? ];
+
+    while parser.eat( Token::Comma )? {
+        items.push( parser.item()? );
+    }
+
+    if parser.tokens.next().is_some() {
+        return Err( FieldError::InvalidArgument );
+    }
+
+    Ok( match items.len() {
+        1 => items.remove( 0 ),
+        _ => CronCommand::List( items )
+    })
+}
+
+/// A recursive descent parser over the tokens of a field
+struct Parser<'a, 'n> {
+    tokens: Tokenizer<'a>,
+    names: &'n dyn Fn( &str ) -> Option<u32>
+}
+
+impl<'a> Parser<'a, '_> {
+    fn next( &mut self ) -> Result<Token<'a>, FieldError> {
+        self.tokens.next().unwrap_or( Err( FieldError::InvalidArgument ) )
+    }
+
+    /// Moves past the next token when it is the expected one
+    fn eat( &mut self, expected: Token ) -> Result<bool, FieldError> {
+        match self.tokens.peek().transpose()? {
+            Some( token ) if token == expected => {
+                self.tokens.next();
+                Ok( true )
+            },
+            _ => Ok( false )
+        }
+    }
+
+    /// `*`, `?`, `/n`, `*/n`, `L`, `L-n`, `LW`, or an item starting with a value
+    fn item( &mut self ) -> Result<CronCommand, FieldError> {
+        match self.next()? {
+            Token::Asterisk if self.eat( Token::Slash )? => Ok( CronCommand::Step( StepBase::Asterisk, self.number()? ) ),
+            Token::Asterisk => Ok( CronCommand::Asterisk ),
+            Token::Question => Ok( CronCommand::NoSpecific ),
+            Token::Slash => Ok( CronCommand::Step( StepBase::Multiple, self.number()? ) ),
+            Token::Word( "L" ) if self.eat( Token::Dash )? => Ok( CronCommand::LastDay( self.number()? ) ),
+            Token::Word( "L" ) => Ok( CronCommand::LastDay( 0 ) ),
+            Token::Word( "LW" ) => Ok( CronCommand::LastWeekday ),
+            Token::Number( n ) if self.eat( Token::Word( "L" ) )? => Ok( CronCommand::L( n ) ),
+            Token::Number( n ) if self.eat( Token::Word( "W" ) )? => Ok( CronCommand::W( n ) ),
+            Token::Number( n ) => self.after_value( n ),
+            Token::Word( name ) => {
+                let value = self.resolve( name )?;
+
+                self.after_value( value )
+            },
+            _ => Err( FieldError::InvalidArgument )
+        }
+    }
+
+    /// `a`, `a#n`, `a-b`, `a-b/n` or `a/n` once the value `a` has been read
+    fn after_value( &mut self, start: u32 ) -> Result<CronCommand, FieldError> {
+        if self.eat( Token::Hash )? {
+            return Ok( CronCommand::Nth( start, self.number()? ) );
+        }
+
+        if self.eat( Token::Dash )? {
+            let end = self.value()?;
+
+            return match self.eat( Token::Slash )? {
+                true => Ok( CronCommand::Step( StepBase::Range( start, end ), self.number()? ) ),
+                false => Ok( CronCommand::Range( start, end ) )
+            };
+        }
+
+        match self.eat( Token::Slash )? {
+            true => Ok( CronCommand::Step( StepBase::Start( start ), self.number()? ) ),
+            false => Ok( CronCommand::Number( start ) )
+        }
+    }
+
+    /// A number or a name
+    fn value( &mut self ) -> Result<u32, FieldError> {
+        match self.next()? {
+            Token::Number( n ) => Ok( n ),
+            Token::Word( name ) => self.resolve( name ),
+            _ => Err( FieldError::InvalidArgument )
+        }
+    }
+
+    fn number( &mut self ) -> Result<u32, FieldError> {
+        match self.next()? {
+            Token::Number( n ) => Ok( n ),
+            _ => Err( FieldError::InvalidArgument )
+        }
+    }
+
+    fn resolve( &self, name: &str ) -> Result<u32, FieldError> {
+        ( self.names )( name ).ok_or( FieldError::InvalidArgument )
+    }
+}
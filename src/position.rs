@@ -1,25 +1,33 @@
 use std::fmt::Display;
 
-use chrono::{DateTime, Utc, TimeZone, Timelike, Datelike, Date};
+use chrono::{Datelike, Days, Duration, NaiveDate, NaiveDateTime, Timelike};
 
+use crate::command::{DayOfWeek, Month};
+use crate::dialect::CronDialect;
 
-#[derive(Debug)]
+
+/// The fields of a cron expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CronPosition {
+    Second,
     Minute,
     Hour,
     DayOfMonth,
     Month,
-    DayOfWeek
+    DayOfWeek,
+    Year
 }
 
 impl Display for CronPosition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let display = match self {
+            CronPosition::Second => "Second",
             CronPosition::Minute => "Minute",
             CronPosition::Hour => "Hour",
             CronPosition::DayOfMonth => "DayOfMonth",
             CronPosition::Month => "Month",
             CronPosition::DayOfWeek => "DayOfWeek",
+            CronPosition::Year => "Year",
         };
 
         write!( f, "{display}" )
@@ -27,103 +35,109 @@ impl Display for CronPosition {
 }
 
 impl CronPosition {
-    pub fn update_date( &self, date: &DateTime<Utc>, next_value: u32 ) -> DateTime<Utc> {
+    /// The start of the next second, minute, hour, day, month or year after the date, `None` past the end of the calendar
+    pub fn next_start( &self, date: &NaiveDateTime ) -> Option<NaiveDateTime> {
         match self {
-            CronPosition::Minute => {
-                let next = date.clone();
-                let current_minute = next.minute();
-
-                if current_minute >= next_value {
-                    return next.with_hour( date.hour() + 1 ).unwrap().with_minute(next_value).unwrap()
-                }
+            CronPosition::Second => date.with_nanosecond( 0 )?.checked_add_signed( Duration::seconds( 1 ) ),
+            CronPosition::Minute => date.with_second( 0 )?.with_nanosecond( 0 )?.checked_add_signed( Duration::minutes( 1 ) ),
+            CronPosition::Hour => date.date().and_hms_opt( date.hour(), 0, 0 )?.checked_add_signed( Duration::hours( 1 ) ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => date.date().succ_opt()?.and_hms_opt( 0, 0, 0 ),
+            CronPosition::Month => {
+                let ( year, month ) = match date.month() {
+                    12 => ( date.year() + 1, 1 ),
+                    month => ( date.year(), month + 1 )
+                };
 
-                next.with_minute(next_value).unwrap()
+                NaiveDate::from_ymd_opt( year, month, 1 )?.and_hms_opt( 0, 0, 0 )
             },
-            CronPosition::Hour => {
-                let next = date.clone();
-                let current_hour = next.hour();
+            CronPosition::Year => NaiveDate::from_ymd_opt( date.year() + 1, 1, 1 )?.and_hms_opt( 0, 0, 0 ),
+        }
+    }
 
-                if current_hour >= next_value {
-                    return next.with_day( date.day() + 1 ).unwrap().with_hour( next_value ).unwrap().with_minute(0).unwrap();
-                }
+    /// The start of the second, minute, hour, day, month or year the date is in
+    pub fn start_of( &self, date: &NaiveDateTime ) -> Option<NaiveDateTime> {
+        match self {
+            CronPosition::Second => date.with_nanosecond( 0 ),
+            CronPosition::Minute => date.with_second( 0 )?.with_nanosecond( 0 ),
+            CronPosition::Hour => date.date().and_hms_opt( date.hour(), 0, 0 ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => date.date().and_hms_opt( 0, 0, 0 ),
+            CronPosition::Month => date.date().with_day( 1 )?.and_hms_opt( 0, 0, 0 ),
+            CronPosition::Year => NaiveDate::from_ymd_opt( date.year(), 1, 1 )?.and_hms_opt( 0, 0, 0 ),
+        }
+    }
 
-                next.with_hour( next_value ).unwrap().with_minute(0).unwrap()
-            },
-            CronPosition::Month => {
-                let next = date.clone();
-                let current_month = next.month();
-
-                if current_month >= next_value {
-                    return next.with_year( date.year() + 1 ).unwrap()
-                        .with_month( next_value ).unwrap()
-                        .with_day( 1 ).unwrap()
-                        .with_hour( 0 ).unwrap()
-                        .with_minute(0).unwrap();
-                }
-
-                next.with_month( next_value ).unwrap()
-                        .with_day( 1 ).unwrap()
-                        .with_hour( 0 ).unwrap()
-                        .with_minute(0).unwrap()
-            },
-            CronPosition::DayOfMonth => {
-                let next = date.clone().with_day(next_value).unwrap().with_hour( 0 ).unwrap().with_minute(0).unwrap();
-
-                if next.lt( date ) {
-                    next.with_month( date.month() + 1 ).unwrap()
-                }
-                else {
-                    next
-                }
-            }
+    /// The start of the unit with the given value, in the same minute, hour, day, month or year as the date.
+    /// A day of week moves forward to the next day with that weekday
+    pub fn start_at( &self, date: &NaiveDateTime, value: u32 ) -> Option<NaiveDateTime> {
+        match self {
+            CronPosition::Second => date.date().and_hms_opt( date.hour(), date.minute(), value ),
+            CronPosition::Minute => date.date().and_hms_opt( date.hour(), value, 0 ),
+            CronPosition::Hour => date.date().and_hms_opt( value, 0, 0 ),
+            CronPosition::DayOfMonth => date.date().with_day( value )?.and_hms_opt( 0, 0, 0 ),
             CronPosition::DayOfWeek => {
-                let current_weekday = date.weekday().num_days_from_sunday();
-                let to_add = if current_weekday < next_value {
-                    next_value - current_weekday
-                }
-                else {
-                    ( current_weekday + 7 ) - next_value
-                };
+                let ahead = ( value + 7 - date.weekday().num_days_from_sunday() ) % 7;
 
+                date.date().checked_add_days( Days::new( ahead.into() ) )?.and_hms_opt( 0, 0, 0 )
+            },
+            CronPosition::Month => NaiveDate::from_ymd_opt( date.year(), value, 1 )?.and_hms_opt( 0, 0, 0 ),
+            CronPosition::Year => NaiveDate::from_ymd_opt( value as i32, 1, 1 )?.and_hms_opt( 0, 0, 0 ),
+        }
+    }
 
-                let days = chrono::Days::new( to_add.into() );
-
-                let next = date.checked_add_days( days ).unwrap()
-                    .with_hour( 0 ).unwrap()
-                    .with_minute(0).unwrap();
+    /// The position whose unit contains this one, `None` for the year
+    pub fn parent( &self ) -> Option<CronPosition> {
+        match self {
+            CronPosition::Second => Some( CronPosition::Minute ),
+            CronPosition::Minute => Some( CronPosition::Hour ),
+            CronPosition::Hour => Some( CronPosition::DayOfMonth ),
+            CronPosition::DayOfMonth | CronPosition::DayOfWeek => Some( CronPosition::Month ),
+            CronPosition::Month => Some( CronPosition::Year ),
+            CronPosition::Year => None,
+        }
+    }
 
-                next
-            }
+    /// The number of a month or weekday name valid for this position, in any case
+    pub fn resolve_name( &self, name: &str, dialect: &CronDialect ) -> Option<u32> {
+        match self {
+            CronPosition::Month => Month::from_name( name ).map( |month| month.index() ),
+            CronPosition::DayOfWeek => DayOfWeek::from_name( name ).map( |day| day.index() + dialect.weekday_offset() ),
+            _ => None
         }
     }
 
     pub fn get_min( &self ) -> u32 {
         match self {
+            CronPosition::Second => 0,
             CronPosition::Minute => 0,
             CronPosition::Hour => 0,
             CronPosition::DayOfMonth => 1,
             CronPosition::Month => 1,
             CronPosition::DayOfWeek => 0,
+            CronPosition::Year => 1970,
         }
     }
 
     pub fn get_max( &self ) -> u32 {
         match self {
+            CronPosition::Second => 59,
             CronPosition::Minute => 59,
             CronPosition::Hour => 23,
             CronPosition::DayOfMonth => 31,
             CronPosition::Month => 12,
             CronPosition::DayOfWeek => 6,
+            CronPosition::Year => 2099,
         }
     }
 
-    pub fn get_value_from_date( &self, date: &DateTime<Utc> ) -> u32 {
+    pub fn get_value_from_date( &self, date: &NaiveDateTime ) -> u32 {
         match self {
+            CronPosition::Second => date.second(),
             CronPosition::Minute => date.minute(),
             CronPosition::Hour => date.hour(),
             CronPosition::DayOfMonth => date.day(),
             CronPosition::Month => date.month(),
             CronPosition::DayOfWeek => date.weekday().num_days_from_sunday(),
+            CronPosition::Year => date.year() as u32,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use croncron::{CronEnglish, CronFrench, CronGerman, CronJapanese, CronLocale, CronSchedule};
+
+/// Describes every expression in the golden directory and compares it with the locale's file,
+/// `UPDATE_GOLDEN=1` writes the descriptions instead
+fn check_golden( name: &str, locale: &dyn CronLocale ){
+    let directory = PathBuf::from( env!( "CARGO_MANIFEST_DIR" ) ).join( "tests/golden" );
+    let expressions = fs::read_to_string( directory.join( "expressions.txt" ) ).unwrap();
+
+    let described: String = expressions.lines()
+        .map( |expression| format!( "{expression}\n{}\n\n", CronSchedule::from_str( expression ).unwrap().describe_in( locale ) ) )
+        .collect();
+
+    let path = directory.join( format!( "describe.{name}.txt" ) );
+
+    if std::env::var_os( "UPDATE_GOLDEN" ).is_some() {
+        fs::write( &path, &described ).unwrap();
+    }
+
+    assert_eq!( described, fs::read_to_string( &path ).unwrap() );
+}
+
+#[test]
+fn should_describe_in_english(){
+    check_golden( "en", &CronEnglish );
+}
+
+#[test]
+fn should_describe_in_german(){
+    check_golden( "de", &CronGerman );
+}
+
+#[test]
+fn should_describe_in_french(){
+    check_golden( "fr", &CronFrench );
+}
+
+#[test]
+fn should_describe_in_japanese(){
+    check_golden( "ja", &CronJapanese );
+}